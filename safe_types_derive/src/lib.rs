@@ -1,7 +1,10 @@
 use proc_macro::{token_stream::IntoIter, TokenStream, TokenTree};
 use quote::quote;
 use syn::parse::Parser;
-use syn::{parse_quote, punctuated::Punctuated, FnArg, ItemFn, Signature, Token};
+use syn::{
+    parse_macro_input, parse_quote, punctuated::Punctuated, FnArg, ForeignItem, ForeignItemFn,
+    Ident, Item, ItemFn, ItemForeignMod, ReturnType, Signature, Token, Type,
+};
 
 /// Implements methods
 #[proc_macro]
@@ -71,6 +74,205 @@ pub fn impl_methods(input: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Generates FFI shims from idiomatic signatures.
+///
+/// On a function, keeps it and adds an `extern "C"` twin, exported under the same name,
+/// where every argument is `<T as FromSafe>::Safe` and the return type is `<R as IntoSafe>::Safe`:
+///
+/// ```ignore
+/// #[safe_extern]
+/// fn handle(name: &str, data: Vec<u8>) -> Result<String, io::Error> { .. }
+/// // exports `extern "C" fn handle(SStr, SVec<u8>) -> SResult<SString, SError>`
+/// ```
+///
+/// On an `extern "C"` block, declares the functions with their FFI-safe signatures
+/// (keeping `#[link_name]` if given) and generates `unsafe` wrappers with the idiomatic ones:
+///
+/// ```ignore
+/// #[safe_extern]
+/// extern "C" {
+///     fn handle(name: &str, data: Vec<u8>) -> Result<String, io::Error>;
+/// }
+/// // `unsafe fn handle(name: &str, data: Vec<u8>) -> Result<String, io::Error>`
+/// ```
+///
+/// Panics can't unwind out of the exported function, use `ffi_catch_unwind` in the body
+/// if they need to be reported.
+#[proc_macro_attribute]
+pub fn safe_extern(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`safe_extern` doesn't take arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let result = match parse_macro_input!(item as Item) {
+        Item::Fn(item) => safe_extern_fn(item),
+        Item::ForeignMod(item) => safe_extern_block(item),
+        item => Err(syn::Error::new_spanned(
+            item,
+            "`safe_extern` can only be used on functions and `extern` blocks",
+        )),
+    };
+
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn safe_extern_fn(item: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    check_signature(&item.sig)?;
+
+    let name = &item.sig.ident;
+    let export_name = name.to_string();
+    let shim = Ident::new(&format!("__safe_extern_{}", name), name.span());
+    let (args, types) = arguments(&item.sig)?;
+    let call = quote! { #name ( #( <#types as ::safe_types::FromSafe>::from_safe(#args) ),* ) };
+    let (output, body) = match &item.sig.output {
+        ReturnType::Default => (quote! {}, quote! { #call; }),
+        ReturnType::Type(_, ty) => (
+            quote! { -> <#ty as ::safe_types::IntoSafe>::Safe },
+            quote! { ::safe_types::IntoSafe::into_safe(#call) },
+        ),
+    };
+
+    Ok(quote! {
+        #item
+
+        #[doc(hidden)]
+        #[export_name = #export_name]
+        extern "C" fn #shim ( #( #args: <#types as ::safe_types::IntoSafe>::Safe ),* ) #output {
+            #body
+        }
+    })
+}
+
+fn safe_extern_block(item: ItemForeignMod) -> syn::Result<proc_macro2::TokenStream> {
+    let abi = &item.abi;
+    let mut declarations = Vec::new();
+    let mut wrappers = Vec::new();
+
+    for foreign_item in &item.items {
+        let ForeignItemFn {
+            attrs, vis, sig, ..
+        } = match foreign_item {
+            ForeignItem::Fn(f) => f,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "`safe_extern` blocks can only contain functions",
+                ))
+            }
+        };
+        check_signature(sig)?;
+
+        let name = &sig.ident;
+        let (link_name, attrs): (Vec<_>, Vec<_>) = attrs
+            .iter()
+            .partition(|attr| attr.path.is_ident("link_name"));
+        let link_name = match link_name.first() {
+            Some(attr) => quote! { #attr },
+            None => {
+                let link_name = name.to_string();
+                quote! { #[link_name = #link_name] }
+            }
+        };
+        let shim = Ident::new(&format!("__safe_extern_{}", name), name.span());
+        let (args, types) = arguments(sig)?;
+        let call = quote! { unsafe { #shim ( #( ::safe_types::IntoSafe::into_safe(#args) ),* ) } };
+        let (output, body) = match &sig.output {
+            ReturnType::Default => (quote! {}, quote! { #call; }),
+            ReturnType::Type(_, ty) => (
+                quote! { -> <#ty as ::safe_types::IntoSafe>::Safe },
+                quote! { ::safe_types::FromSafe::from_safe(#call) },
+            ),
+        };
+
+        let mut wrapper_sig = sig.clone();
+        wrapper_sig.unsafety = Some(parse_quote! { unsafe });
+        for (input, arg) in wrapper_sig.inputs.iter_mut().zip(&args) {
+            if let FnArg::Typed(pat) = input {
+                pat.pat = parse_quote! { #arg };
+            }
+        }
+
+        declarations.push(quote! {
+            #link_name
+            fn #shim ( #( #args: <#types as ::safe_types::IntoSafe>::Safe ),* ) #output;
+        });
+        wrappers.push(quote! {
+            #( #attrs )*
+            #vis #wrapper_sig {
+                #body
+            }
+        });
+    }
+
+    let attrs = &item.attrs;
+
+    Ok(quote! {
+        #( #attrs )*
+        #abi {
+            #( #declarations )*
+        }
+
+        #( #wrappers )*
+    })
+}
+
+fn check_signature(sig: &Signature) -> syn::Result<()> {
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &sig.generics,
+            "`safe_extern` functions can't be generic",
+        ));
+    }
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "`safe_extern` functions can't be async",
+        ));
+    }
+    if let Some(variadic) = &sig.variadic {
+        return Err(syn::Error::new_spanned(
+            variadic,
+            "`safe_extern` functions can't be variadic",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Names every argument `argN`, since patterns can't be reused in the shim
+fn arguments(sig: &Signature) -> syn::Result<(Vec<Ident>, Vec<Type>)> {
+    let mut args = Vec::new();
+    let mut types = Vec::new();
+
+    for (i, arg) in sig.inputs.iter().enumerate() {
+        match arg {
+            FnArg::Typed(pat) => {
+                args.push(Ident::new(
+                    &format!("arg{}", i),
+                    proc_macro2::Span::call_site(),
+                ));
+                types.push((*pat.ty).clone());
+            }
+            FnArg::Receiver(rcv) => {
+                return Err(syn::Error::new_spanned(
+                    rcv,
+                    "`safe_extern` functions can't take `self`",
+                ))
+            }
+        }
+    }
+
+    Ok((args, types))
+}
+
 fn get_ident(token_iter: &mut IntoIter) -> proc_macro2::Ident {
     if let TokenTree::Ident(ident) = token_iter.next().unwrap() {
         proc_macro2::Ident::new(&format!("{}", ident), proc_macro2::Span::call_site())
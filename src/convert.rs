@@ -0,0 +1,246 @@
+use crate::{
+    std::{
+        borrow::SCow,
+        boxed::SBox,
+        cell::{SBorrowError, SBorrowMutError, SCell, SRefCell},
+        error::SDynError,
+        future::{SFuture, SLocalFuture, SStream},
+        io::{SError, SErrorKind},
+        net::{
+            SIpAddr, SIpv4Addr, SIpv6Addr, SShutdown, SSocketAddr, SSocketAddrV4, SSocketAddrV6,
+            STcpStream,
+        },
+        option::SOption,
+        panic::{SLocation, SPanicInfo},
+        rc::{SRc, SRcOpaque, SWeakRc, SWeakRcOpaque},
+        result::SResult,
+        string::SString,
+        sync::{
+            atomic::*,
+            mpsc::{SReceiver, SRecvError, SRecvTimeoutError, SSendError, SSender, STryRecvError},
+            SArc, SArcOpaque, SCondvar, SLazy, SMutex, SOnceLock, SPoisonError, SRwLock,
+            STryLockError, SWaitTimeoutResult, SWeak, SWeakOpaque,
+        },
+        task::{SPoll, SWaker},
+        thread::{SJoinHandle, SThread, SThreadId},
+        time::SDuration,
+        vec::SVec,
+    },
+    SArray, SMutSlice, SMutStr, SSlice, SStr, STuple10, STuple11, STuple12, STuple2, STuple3,
+    STuple4, STuple5, STuple6, STuple7, STuple8, STuple9, SUnit,
+};
+use std::ops::Deref;
+
+/// Conversion of a std type into its FFI-safe equivalent.
+///
+/// Unlike the `From` impls on each type, this converts recursively,
+/// so `Result<Vec<String>, std::io::Error>` becomes
+/// `SResult<SVec<SString>, SError>` with a single call.
+///
+/// [`IntoSafe::Safe`] can be used to name the FFI-safe equivalent of any std type
+/// in generic code.
+///
+/// Borrowed slices (`&[T]`, `&mut [T]`) can't convert their elements, so `T`
+/// must already be FFI-safe there.
+///
+/// The crate's own FFI-safe types convert to themselves, so a value can be converted
+/// even if some of its parts already are. Their contents are left as they are.
+/// Guards and [`SContext`](crate::std::task::SContext) only live for a borrow,
+/// and [`SRawSlice`](crate::SRawSlice) and [`SRawStr`](crate::SRawStr) are unsized,
+/// so they are left out.
+pub trait IntoSafe {
+    /// The FFI-safe equivalent of `Self`
+    type Safe;
+
+    fn into_safe(self) -> Self::Safe;
+}
+
+/// Conversion of an FFI-safe type back into its std equivalent.
+///
+/// This is the reverse of [`IntoSafe`].
+pub trait FromSafe: IntoSafe {
+    fn from_safe(safe: Self::Safe) -> Self;
+}
+
+macro_rules! impl_identity {
+    ($($(#[$attr:meta])* [$($g:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<$($g)*> IntoSafe for $ty {
+                type Safe = $ty;
+
+                fn into_safe(self) -> Self::Safe {
+                    self
+                }
+            }
+            $(#[$attr])*
+            impl<$($g)*> FromSafe for $ty {
+                fn from_safe(safe: Self::Safe) -> Self {
+                    safe
+                }
+            }
+        )*
+    };
+    ($($ty:ty),*) => {
+        $(
+            impl IntoSafe for $ty {
+                type Safe = $ty;
+
+                fn into_safe(self) -> Self::Safe {
+                    self
+                }
+            }
+            impl FromSafe for $ty {
+                fn from_safe(safe: Self::Safe) -> Self {
+                    safe
+                }
+            }
+        )*
+    };
+}
+
+impl_identity!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, bool, char);
+
+impl_identity! {
+    [] SUnit,
+    [T, const N: usize] SArray<T, N>,
+    ['a, T] SSlice<'a, T>,
+    ['a, T] SMutSlice<'a, T>,
+    ['a] SStr<'a>,
+    ['a] SMutStr<'a>,
+    [T] SBox<T>,
+    [T] SCell<T>,
+    [T] SRefCell<T>,
+    [] SBorrowError,
+    [] SBorrowMutError,
+    [] SDynError,
+    [T] SFuture<T>,
+    [T] SLocalFuture<T>,
+    [T] SStream<T>,
+    [] SError,
+    [] SErrorKind,
+    [] SIpAddr,
+    [] SIpv4Addr,
+    [] SIpv6Addr,
+    [] SShutdown,
+    [] SSocketAddr,
+    [] SSocketAddrV4,
+    [] SSocketAddrV6,
+    [] STcpStream,
+    [T] SOption<T>,
+    [] SLocation,
+    [] SPanicInfo,
+    [T] SRc<T>,
+    [T] SWeakRc<T>,
+    [] SRcOpaque,
+    [] SWeakRcOpaque,
+    [T, E] SResult<T, E>,
+    [] SString,
+    [T] SArc<T>,
+    [T] SWeak<T>,
+    [] SArcOpaque,
+    [] SWeakOpaque,
+    [] SCondvar,
+    [] SWaitTimeoutResult,
+    [T, F] SLazy<T, F>,
+    [T] SMutex<T>,
+    [T] SOnceLock<T>,
+    [T] SPoisonError<T>,
+    [T] SRwLock<T>,
+    [T] STryLockError<T>,
+    [T] SReceiver<T>,
+    [T] SSender<T>,
+    [T] SSendError<T>,
+    [] SRecvError,
+    [] SRecvTimeoutError,
+    [] STryRecvError,
+    #[cfg(target_has_atomic = "8")]
+    [] SAtomicBool,
+    #[cfg(target_has_atomic = "32")]
+    [] SAtomicU32,
+    #[cfg(target_has_atomic = "32")]
+    [] SAtomicI32,
+    #[cfg(target_has_atomic = "64")]
+    [] SAtomicU64,
+    #[cfg(target_has_atomic = "64")]
+    [] SAtomicI64,
+    #[cfg(target_has_atomic = "ptr")]
+    [] SAtomicUsize,
+    #[cfg(target_has_atomic = "ptr")]
+    [] SAtomicIsize,
+    #[cfg(target_has_atomic = "ptr")]
+    [T] SAtomicPtr<T>,
+    [T] SPoll<T>,
+    [] SWaker,
+    [T] SJoinHandle<T>,
+    [] SThread,
+    [] SThreadId,
+    [] SDuration,
+    [T] SVec<T>,
+}
+
+impl<B> IntoSafe for SCow<B>
+where
+    B: Deref,
+    <B as Deref>::Target: ToOwned,
+{
+    type Safe = Self;
+
+    fn into_safe(self) -> Self::Safe {
+        self
+    }
+}
+impl<B> FromSafe for SCow<B>
+where
+    B: Deref,
+    <B as Deref>::Target: ToOwned,
+{
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe
+    }
+}
+
+impl IntoSafe for () {
+    type Safe = SUnit;
+
+    fn into_safe(self) -> Self::Safe {
+        SUnit::new()
+    }
+}
+impl FromSafe for () {
+    fn from_safe(_: Self::Safe) -> Self {}
+}
+
+macro_rules! impl_tuples {
+    ($($name:ident($($t:ident $i:tt),+);)*) => {
+        $(
+            impl<$($t: IntoSafe),+> IntoSafe for ($($t,)+) {
+                type Safe = $name<$($t::Safe),+>;
+
+                fn into_safe(self) -> Self::Safe {
+                    $name($(self.$i.into_safe()),+)
+                }
+            }
+            impl<$($t: FromSafe),+> FromSafe for ($($t,)+) {
+                fn from_safe(safe: Self::Safe) -> Self {
+                    ($($t::from_safe(safe.$i),)+)
+                }
+            }
+            impl_identity!([$($t),+] $name<$($t),+>);
+        )*
+    };
+}
+
+impl_tuples! {
+    STuple2(A 0, B 1);
+    STuple3(A 0, B 1, C 2);
+    STuple4(A 0, B 1, C 2, D 3);
+    STuple5(A 0, B 1, C 2, D 3, E 4);
+    STuple6(A 0, B 1, C 2, D 3, E 4, F 5);
+    STuple7(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    STuple8(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    STuple9(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    STuple10(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    STuple11(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    STuple12(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+}
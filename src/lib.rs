@@ -1,5 +1,6 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 
+mod convert;
 mod refs;
 mod sarray;
 mod sslice;
@@ -7,13 +8,17 @@ mod sstr;
 pub mod std;
 mod sunit;
 mod tuples;
+mod type_tag;
 
+pub use convert::{FromSafe, IntoSafe};
+#[cfg(feature = "safe_extern")]
+pub use safe_types_derive::safe_extern;
 pub use refs::{Immutable, Mutable};
 pub use sarray::SArray;
-pub use sslice::{SMutSlice, SSlice};
+pub use sslice::{SMutSlice, SRawSlice, SSlice};
 pub use sstr::{SMutStr, SRawStr, SStr};
 pub use sunit::SUnit;
 pub use tuples::*;
 
 /// This will increase when incompatible ABI changes are made.
-pub const ABI_VERSION: u32 = 0;
+pub const ABI_VERSION: u32 = 14;
@@ -53,7 +53,9 @@ impl<'a, O, T: Into<O>> DerefMut for Mutable<'a, O, T> {
 }
 impl<'a, O, T: Into<O>> Drop for Mutable<'a, O, T> {
     fn drop(&mut self) {
-        *self.original = unsafe { ManuallyDrop::take(&mut self.inner) }.into();
+        // `original` was moved out of with `ptr::read` on construction,
+        // so it must be overwritten without dropping it
+        unsafe { std::ptr::write(self.original, ManuallyDrop::take(&mut self.inner).into()) };
     }
 }
 impl<'a, O, T: Into<O>> Mutable<'a, O, T> {
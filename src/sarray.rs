@@ -1,3 +1,4 @@
+use crate::{FromSafe, IntoSafe};
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 
@@ -140,3 +141,16 @@ impl<T, const N: usize> IntoIterator for SArray<T, N> {
         self.inner.into_iter()
     }
 }
+
+impl<T: IntoSafe, const N: usize> IntoSafe for [T; N] {
+    type Safe = SArray<T::Safe, N>;
+
+    fn into_safe(self) -> Self::Safe {
+        SArray::from_array(self.map(IntoSafe::into_safe))
+    }
+}
+impl<T: FromSafe, const N: usize> FromSafe for [T; N] {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_array().map(T::from_safe)
+    }
+}
@@ -1,4 +1,8 @@
+use crate::{std::vec::SVec, FromSafe, IntoSafe};
 use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
     marker::PhantomData,
     ops::{Deref, DerefMut, Index, IndexMut},
 };
@@ -30,6 +34,76 @@ pub struct SMutSlice<'a, T> {
     _phantom_d: PhantomData<&'a T>,
 }
 
+/// Equivalent of `[T]`. Unsized and references to it are **not FFI-safe**.
+///
+/// Needed to mark that `< < SSlice<'a, T> as Deref >::Target as ToOwned >::Owned = SVec<T>`
+/// instead of the std Vec
+#[repr(transparent)]
+pub struct SRawSlice<T> {
+    pub inner: [T],
+}
+
+impl<T> SRawSlice<T> {
+    pub fn from_slice(s: &[T]) -> &SRawSlice<T> {
+        unsafe { &*(s as *const [T] as *const SRawSlice<T>) }
+    }
+    pub fn from_mut_slice(s: &mut [T]) -> &mut SRawSlice<T> {
+        unsafe { &mut *(s as *mut [T] as *mut SRawSlice<T>) }
+    }
+    pub fn into_slice(&self) -> &[T] {
+        &self.inner
+    }
+    pub fn into_mut_slice(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+}
+impl<T> Deref for SRawSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+impl<T> DerefMut for SRawSlice<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+impl<T: Clone> ToOwned for SRawSlice<T> {
+    type Owned = SVec<T>;
+
+    fn to_owned(&self) -> Self::Owned {
+        SVec::from_vec(self.inner.to_vec())
+    }
+}
+
+impl<T: Debug> Debug for SRawSlice<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+impl<T: PartialEq> PartialEq for SRawSlice<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+impl<T: Eq> Eq for SRawSlice<T> {}
+impl<T: PartialOrd> PartialOrd for SRawSlice<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+impl<T: Ord> Ord for SRawSlice<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+impl<T: Hash> Hash for SRawSlice<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
 impl<'a, T> SSlice<'a, T> {
     pub fn from_slice(slice: &[T]) -> Self {
         Self {
@@ -44,10 +118,10 @@ impl<'a, T> SSlice<'a, T> {
 }
 
 impl<'a, T> Deref for SSlice<'a, T> {
-    type Target = [T];
+    type Target = SRawSlice<T>;
 
     fn deref(&self) -> &Self::Target {
-        self.as_slice()
+        SRawSlice::from_slice(self.as_slice())
     }
 }
 
@@ -62,25 +136,25 @@ impl<'a, T> SMutSlice<'a, T> {
     pub fn into_slice(self) -> &'a mut [T] {
         unsafe { std::slice::from_raw_parts_mut(self.ptr, self.length) }
     }
-    pub fn as_slice<'b>(&'b self) -> &'b [T] {
+    pub fn as_slice(&self) -> &[T] {
         unsafe { std::slice::from_raw_parts(self.ptr, self.length) }
     }
-    pub fn as_slice_mut<'b>(&'b mut self) -> &'b mut [T] {
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
         unsafe { std::slice::from_raw_parts_mut(self.ptr, self.length) }
     }
 }
 
 impl<'a, T> Deref for SMutSlice<'a, T> {
-    type Target = [T];
+    type Target = SRawSlice<T>;
 
     fn deref(&self) -> &Self::Target {
-        self.as_slice()
+        SRawSlice::from_slice(self.as_slice())
     }
 }
 
 impl<'a, T> DerefMut for SMutSlice<'a, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        self.as_slice_mut()
+        SRawSlice::from_mut_slice(self.as_slice_mut())
     }
 }
 
@@ -101,6 +175,18 @@ impl<'a, T> From<SSlice<'a, T>> for &'a [T] {
     }
 }
 
+impl<'a, T> From<&'a SRawSlice<T>> for SSlice<'a, T> {
+    fn from(s: &'a SRawSlice<T>) -> Self {
+        Self::from_slice(s.into_slice())
+    }
+}
+
+impl<'a, T> From<SSlice<'a, T>> for &'a SRawSlice<T> {
+    fn from(s: SSlice<'a, T>) -> Self {
+        SRawSlice::from_slice(s.as_slice())
+    }
+}
+
 impl<'a, T> From<&'a mut [T]> for SMutSlice<'a, T> {
     fn from(s: &'a mut [T]) -> Self {
         Self::from_slice(s)
@@ -157,4 +243,30 @@ unsafe impl<'a, T> Sync for SSlice<'a, T> {}
 unsafe impl<'a, T> Send for SMutSlice<'a, T> {}
 unsafe impl<'a, T> Sync for SMutSlice<'a, T> {}
 
+impl<'a, T> IntoSafe for &'a [T] {
+    type Safe = SSlice<'a, T>;
+
+    fn into_safe(self) -> Self::Safe {
+        SSlice::from_slice(self)
+    }
+}
+impl<T> FromSafe for &[T] {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_slice()
+    }
+}
+
+impl<'a, T> IntoSafe for &'a mut [T] {
+    type Safe = SMutSlice<'a, T>;
+
+    fn into_safe(self) -> Self::Safe {
+        SMutSlice::from_slice(self)
+    }
+}
+impl<T> FromSafe for &mut [T] {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_slice()
+    }
+}
+
 // TODO more trait impls
@@ -1,6 +1,8 @@
-use crate::{std::string::SString, SMutSlice, SSlice};
+use crate::{std::string::SString, FromSafe, IntoSafe, SMutSlice, SSlice};
 use std::{
+    cmp::Ordering,
     fmt::{Debug, Display},
+    hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
 };
 
@@ -27,6 +29,7 @@ pub struct SRawStr {
 }
 
 impl SRawStr {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str<'a>(s: &'a str) -> &'a SRawStr {
         unsafe { std::mem::transmute::<&'a str, &'a SRawStr>(s) }
     }
@@ -60,7 +63,45 @@ impl ToOwned for SRawStr {
     }
 }
 
+impl Display for SRawStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+impl Debug for SRawStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+impl PartialEq for SRawStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+impl PartialEq<str> for SRawStr {
+    fn eq(&self, other: &str) -> bool {
+        &self.inner == other
+    }
+}
+impl Eq for SRawStr {}
+impl PartialOrd for SRawStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for SRawStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+impl Hash for SRawStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
 impl<'a> SStr<'a> {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &'a str) -> Self {
         Self {
             inner: SSlice::from_slice(s.as_bytes()),
@@ -70,7 +111,7 @@ impl<'a> SStr<'a> {
     where
         'a: 'b,
     {
-        unsafe { &std::str::from_utf8_unchecked(self.inner.as_slice()) }
+        unsafe { std::str::from_utf8_unchecked(self.inner.as_slice()) }
     }
     pub fn into_str(self) -> &'a str {
         unsafe { std::str::from_utf8_unchecked(self.inner.as_slice()) }
@@ -78,6 +119,7 @@ impl<'a> SStr<'a> {
 }
 
 impl<'a> SMutStr<'a> {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &'a mut str) -> Self {
         Self {
             inner: SMutSlice::from_slice(unsafe { s.as_bytes_mut() }),
@@ -90,7 +132,7 @@ impl<'a> SMutStr<'a> {
     where
         'a: 'b,
     {
-        unsafe { &std::str::from_utf8_unchecked(self.inner.as_slice()) }
+        unsafe { std::str::from_utf8_unchecked(self.inner.as_slice()) }
     }
     pub fn as_str_mut<'b>(&'b mut self) -> &'b mut str
     where
@@ -146,4 +188,42 @@ impl<'a> Debug for SMutStr<'a> {
     }
 }
 
+impl<'a> From<&'a SRawStr> for SStr<'a> {
+    fn from(s: &'a SRawStr) -> Self {
+        Self::from_str(s.into_str())
+    }
+}
+
+impl<'a> From<SStr<'a>> for &'a SRawStr {
+    fn from(s: SStr<'a>) -> Self {
+        SRawStr::from_str(s.into_str())
+    }
+}
+
+impl<'a> IntoSafe for &'a str {
+    type Safe = SStr<'a>;
+
+    fn into_safe(self) -> Self::Safe {
+        SStr::from_str(self)
+    }
+}
+impl FromSafe for &str {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_str()
+    }
+}
+
+impl<'a> IntoSafe for &'a mut str {
+    type Safe = SMutStr<'a>;
+
+    fn into_safe(self) -> Self::Safe {
+        SMutStr::from_str(self)
+    }
+}
+impl FromSafe for &mut str {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_str()
+    }
+}
+
 // TODO: add more trait impls
@@ -1,7 +1,10 @@
-use crate::{Immutable, Mutable};
+use crate::{std::string::SString, FromSafe, Immutable, IntoSafe, Mutable, SStr};
 use std::{
     borrow::{Borrow, Cow},
-    ops::Deref,
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Deref},
 };
 
 /// A clone-on-write smart pointer.
@@ -77,22 +80,52 @@ where
     }
 }
 
-impl<'a, B: 'a + Deref> Clone for SCow<B>
+impl<B> SCow<B>
+where
+    B: Deref,
+    <B as Deref>::Target: ToOwned,
+{
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+    pub fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+    pub fn to_mut(&mut self) -> &mut <<B as Deref>::Target as ToOwned>::Owned {
+        if let Self::Borrowed(r) = self {
+            *self = Self::Owned((**r).to_owned());
+        }
+
+        match self {
+            Self::Owned(o) => o,
+            // SAFETY: a `Borrowed` variant for `self` would have been replaced by an `Owned`
+            // variant in the code above.
+            Self::Borrowed(_) => unsafe { std::hint::unreachable_unchecked() },
+        }
+    }
+    pub fn into_owned(self) -> <<B as Deref>::Target as ToOwned>::Owned {
+        match self {
+            Self::Borrowed(r) => (*r).to_owned(),
+            Self::Owned(o) => o,
+        }
+    }
+}
+
+impl<B: Deref + Clone> Clone for SCow<B>
 where
     <B as Deref>::Target: ToOwned,
-    B: Into<&'a <B as Deref>::Target>,
-    &'a <B as Deref>::Target: Into<B>,
 {
     fn clone(&self) -> Self {
-        self.as_cow().clone().into()
+        match self {
+            Self::Borrowed(r) => Self::Borrowed(r.clone()),
+            Self::Owned(o) => Self::Owned((*o).borrow().to_owned()),
+        }
     }
 }
 
-impl<'a, B: 'a + Deref> Deref for SCow<B>
+impl<B: Deref> Deref for SCow<B>
 where
     <B as Deref>::Target: ToOwned,
-    B: Into<&'a <B as Deref>::Target>,
-    &'a <B as Deref>::Target: Into<B>,
 {
     type Target = <B as Deref>::Target;
 
@@ -103,3 +136,160 @@ where
         }
     }
 }
+
+impl<B: Deref> AsRef<<B as Deref>::Target> for SCow<B>
+where
+    <B as Deref>::Target: ToOwned,
+{
+    fn as_ref(&self) -> &<B as Deref>::Target {
+        self
+    }
+}
+
+impl<B: Deref> Debug for SCow<B>
+where
+    <B as Deref>::Target: ToOwned + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<B: Deref> Display for SCow<B>
+where
+    <B as Deref>::Target: ToOwned + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<B: Deref> Default for SCow<B>
+where
+    <B as Deref>::Target: ToOwned,
+    <<B as Deref>::Target as ToOwned>::Owned: Default,
+{
+    fn default() -> Self {
+        Self::Owned(Default::default())
+    }
+}
+
+impl<B: Deref, C: Deref> PartialEq<SCow<C>> for SCow<B>
+where
+    <B as Deref>::Target: ToOwned + PartialEq<<C as Deref>::Target>,
+    <C as Deref>::Target: ToOwned,
+{
+    fn eq(&self, other: &SCow<C>) -> bool {
+        PartialEq::eq(&**self, &**other)
+    }
+}
+
+impl<B: Deref> Eq for SCow<B> where <B as Deref>::Target: ToOwned + Eq {}
+
+impl<B: Deref> PartialOrd for SCow<B>
+where
+    <B as Deref>::Target: ToOwned + PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+impl<B: Deref> Ord for SCow<B>
+where
+    <B as Deref>::Target: ToOwned + Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<B: Deref> Hash for SCow<B>
+where
+    <B as Deref>::Target: ToOwned + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state)
+    }
+}
+
+impl<'a> From<&'a str> for SCow<SStr<'a>> {
+    fn from(s: &'a str) -> Self {
+        Self::Borrowed(SStr::from_str(s))
+    }
+}
+
+impl<'a> From<SStr<'a>> for SCow<SStr<'a>> {
+    fn from(s: SStr<'a>) -> Self {
+        Self::Borrowed(s)
+    }
+}
+
+impl<'a> From<String> for SCow<SStr<'a>> {
+    fn from(s: String) -> Self {
+        Self::Owned(SString::from_string(s))
+    }
+}
+
+impl<'a> From<SString> for SCow<SStr<'a>> {
+    fn from(s: SString) -> Self {
+        Self::Owned(s)
+    }
+}
+
+impl<'a> Add<&'a str> for SCow<SStr<'a>> {
+    type Output = SCow<SStr<'a>>;
+
+    fn add(mut self, rhs: &'a str) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<'a> Add<SCow<SStr<'a>>> for SCow<SStr<'a>> {
+    type Output = SCow<SStr<'a>>;
+
+    fn add(mut self, rhs: SCow<SStr<'a>>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<'a> AddAssign<&'a str> for SCow<SStr<'a>> {
+    fn add_assign(&mut self, rhs: &'a str) {
+        if self.is_empty() {
+            *self = Self::Borrowed(SStr::from_str(rhs));
+        } else if !rhs.is_empty() {
+            self.to_mut().as_string_mut().push_str(rhs);
+        }
+    }
+}
+
+impl<'a> AddAssign<SCow<SStr<'a>>> for SCow<SStr<'a>> {
+    fn add_assign(&mut self, rhs: SCow<SStr<'a>>) {
+        if self.is_empty() {
+            *self = rhs;
+        } else if !rhs.is_empty() {
+            self.to_mut().as_string_mut().push_str(&rhs);
+        }
+    }
+}
+
+impl<'a> IntoSafe for Cow<'a, str> {
+    type Safe = SCow<SStr<'a>>;
+
+    fn into_safe(self) -> Self::Safe {
+        match self {
+            Cow::Borrowed(r) => SCow::Borrowed(SStr::from_str(r)),
+            Cow::Owned(o) => SCow::Owned(SString::from_string(o)),
+        }
+    }
+}
+impl<'a> FromSafe for Cow<'a, str> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        match safe {
+            SCow::Borrowed(r) => Cow::Borrowed(r.into_str()),
+            SCow::Owned(o) => Cow::Owned(o.into_string()),
+        }
+    }
+}
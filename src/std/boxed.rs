@@ -1,4 +1,6 @@
-use crate::{Immutable, Mutable};
+use crate::{FromSafe, Immutable, IntoSafe, Mutable};
+use std::mem::forget;
+use std::ops::{Deref, DerefMut};
 
 /// A pointer type for heap allocation.
 ///
@@ -18,7 +20,11 @@ impl<T> SBox<T> {
         }
     }
     pub fn into_box(self) -> Box<T> {
-        unsafe { Box::from_raw(self.ptr) }
+        let r = unsafe { Box::from_raw(self.ptr) };
+
+        forget(self);
+
+        r
     }
     pub fn as_box<'a>(&'a self) -> Immutable<'a, Box<T>> {
         Immutable::new(unsafe { Box::from_raw(self.ptr) })
@@ -28,6 +34,23 @@ impl<T> SBox<T> {
     }
 }
 
+impl<T> Deref for SBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.ptr }
+    }
+}
+
+impl<T> DerefMut for SBox<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.ptr }
+    }
+}
+
+unsafe impl<T: Send> Send for SBox<T> {}
+unsafe impl<T: Sync> Sync for SBox<T> {}
+
 impl<T> From<Box<T>> for SBox<T> {
     fn from(b: Box<T>) -> Self {
         Self::from_box(b)
@@ -43,7 +66,20 @@ impl<T: Clone> Clone for SBox<T> {
 impl<T> Drop for SBox<T> {
     fn drop(&mut self) {
         unsafe {
-            Box::from_raw(self.ptr);
+            drop(Box::from_raw(self.ptr));
         }
     }
 }
+
+impl<T: IntoSafe> IntoSafe for Box<T> {
+    type Safe = SBox<T::Safe>;
+
+    fn into_safe(self) -> Self::Safe {
+        SBox::from_box(Box::new((*self).into_safe()))
+    }
+}
+impl<T: FromSafe> FromSafe for Box<T> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        Box::new(T::from_safe(*safe.into_box()))
+    }
+}
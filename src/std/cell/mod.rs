@@ -0,0 +1,5 @@
+mod scell;
+mod srefcell;
+
+pub use scell::SCell;
+pub use srefcell::{SBorrowError, SBorrowMutError, SRef, SRefCell, SRefMut};
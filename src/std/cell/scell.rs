@@ -0,0 +1,99 @@
+use crate::{FromSafe, IntoSafe};
+use std::{
+    cell::Cell,
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+/// A mutable memory location.
+///
+/// See documentation of [`std::cell::Cell`]
+///
+/// `Cell<T>` has the same in-memory representation as `T`, so this is a
+/// `#[repr(transparent)]` wrapper that dereferences to it.
+#[derive(Default)]
+#[repr(transparent)]
+pub struct SCell<T: ?Sized>(Cell<T>);
+
+impl<T> SCell<T> {
+    pub const fn new(value: T) -> Self {
+        Self(Cell::new(value))
+    }
+    pub const fn from_cell(cell: Cell<T>) -> Self {
+        Self(cell)
+    }
+    pub fn into_cell(self) -> Cell<T> {
+        self.0
+    }
+    pub fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+    pub fn as_cell(&self) -> &Cell<T> {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> Deref for SCell<T> {
+    type Target = Cell<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for SCell<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Copy> Clone for SCell<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.get())
+    }
+}
+
+impl<T: Copy + PartialEq> PartialEq for SCell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: Copy + Eq> Eq for SCell<T> {}
+
+impl<T: Copy + Debug> Debug for SCell<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SCell").field("value", &self.get()).finish()
+    }
+}
+
+impl<T> From<T> for SCell<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<Cell<T>> for SCell<T> {
+    fn from(cell: Cell<T>) -> Self {
+        Self::from_cell(cell)
+    }
+}
+
+impl<T> From<SCell<T>> for Cell<T> {
+    fn from(cell: SCell<T>) -> Self {
+        cell.into_cell()
+    }
+}
+
+impl<T: IntoSafe> IntoSafe for Cell<T> {
+    type Safe = SCell<T::Safe>;
+
+    fn into_safe(self) -> Self::Safe {
+        SCell::new(self.into_inner().into_safe())
+    }
+}
+impl<T: FromSafe> FromSafe for Cell<T> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        Cell::new(T::from_safe(safe.into_inner()))
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{std::result::SResult, FromSafe, IntoSafe};
+use std::{
+    cell::{BorrowError, BorrowMutError, Cell, RefCell, UnsafeCell},
+    error::Error,
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// A mutable memory location with dynamically checked borrow rules.
+///
+/// See documentation of [`std::cell::RefCell`]
+///
+/// The borrow flag has a defined layout: positive values are the number of
+/// shared borrows, `-1` is a mutable borrow and `0` means not borrowed.
+#[repr(C)]
+pub struct SRefCell<T> {
+    borrow: Cell<isize>,
+    value: UnsafeCell<T>,
+}
+
+/// Wraps a borrowed reference to a value in an [`SRefCell`].
+///
+/// See documentation of [`std::cell::Ref`]
+#[repr(C)]
+pub struct SRef<'b, T> {
+    value: *const T,
+    borrow: &'b Cell<isize>,
+}
+
+/// A wrapper type for a mutably borrowed value from an [`SRefCell`].
+///
+/// See documentation of [`std::cell::RefMut`]
+#[repr(C)]
+pub struct SRefMut<'b, T> {
+    value: *mut T,
+    borrow: &'b Cell<isize>,
+    _marker: PhantomData<&'b mut T>,
+}
+
+/// An error returned by [`SRefCell::try_borrow`].
+///
+/// See documentation of [`std::cell::BorrowError`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct SBorrowError {
+    _private: u8,
+}
+
+/// An error returned by [`SRefCell::try_borrow_mut`].
+///
+/// See documentation of [`std::cell::BorrowMutError`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct SBorrowMutError {
+    _private: u8,
+}
+
+const UNUSED: isize = 0;
+const WRITING: isize = -1;
+
+impl<T> SRefCell<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            borrow: Cell::new(UNUSED),
+            value: UnsafeCell::new(value),
+        }
+    }
+    pub fn from_refcell(cell: RefCell<T>) -> Self {
+        Self::new(cell.into_inner())
+    }
+    pub fn into_refcell(self) -> RefCell<T> {
+        RefCell::new(self.into_inner())
+    }
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+    pub fn borrow(&self) -> SRef<'_, T> {
+        match self.try_borrow() {
+            SResult::Ok(r) => r,
+            SResult::Err(e) => panic!("{e}"),
+        }
+    }
+    pub fn try_borrow(&self) -> SResult<SRef<'_, T>, SBorrowError> {
+        let borrow = self.borrow.get();
+        if borrow < UNUSED || borrow == isize::MAX {
+            return SResult::Err(SBorrowError::new());
+        }
+        self.borrow.set(borrow + 1);
+
+        SResult::Ok(SRef {
+            value: self.value.get(),
+            borrow: &self.borrow,
+        })
+    }
+    pub fn borrow_mut(&self) -> SRefMut<'_, T> {
+        match self.try_borrow_mut() {
+            SResult::Ok(r) => r,
+            SResult::Err(e) => panic!("{e}"),
+        }
+    }
+    pub fn try_borrow_mut(&self) -> SResult<SRefMut<'_, T>, SBorrowMutError> {
+        if self.borrow.get() != UNUSED {
+            return SResult::Err(SBorrowMutError::new());
+        }
+        self.borrow.set(WRITING);
+
+        SResult::Ok(SRefMut {
+            value: self.value.get(),
+            borrow: &self.borrow,
+            _marker: PhantomData,
+        })
+    }
+    pub fn as_ptr(&self) -> *mut T {
+        self.value.get()
+    }
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+    pub fn replace(&self, value: T) -> T {
+        std::mem::replace(&mut *self.borrow_mut(), value)
+    }
+    pub fn replace_with<F: FnOnce(&mut T) -> T>(&self, f: F) -> T {
+        let mut borrow = self.borrow_mut();
+        let value = f(&mut borrow);
+
+        std::mem::replace(&mut *borrow, value)
+    }
+    pub fn swap(&self, other: &Self) {
+        std::mem::swap(&mut *self.borrow_mut(), &mut *other.borrow_mut())
+    }
+}
+
+impl<T: Default> SRefCell<T> {
+    pub fn take(&self) -> T {
+        self.replace(T::default())
+    }
+}
+
+impl<'b, T> SRef<'b, T> {
+    /// Copies an `SRef`.
+    ///
+    /// See documentation of [`std::cell::Ref::clone`]
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(orig: &Self) -> Self {
+        orig.borrow.set(orig.borrow.get() + 1);
+
+        Self {
+            value: orig.value,
+            borrow: orig.borrow,
+        }
+    }
+    /// Makes a new `SRef` for a component of the borrowed data.
+    ///
+    /// See documentation of [`std::cell::Ref::map`]
+    pub fn map<U, F: FnOnce(&T) -> &U>(orig: Self, f: F) -> SRef<'b, U> {
+        let orig = std::mem::ManuallyDrop::new(orig);
+
+        SRef {
+            value: f(unsafe { &*orig.value }),
+            borrow: orig.borrow,
+        }
+    }
+}
+
+impl<'b, T> SRefMut<'b, T> {
+    /// Makes a new `SRefMut` for a component of the borrowed data.
+    ///
+    /// See documentation of [`std::cell::RefMut::map`]
+    pub fn map<U, F: FnOnce(&mut T) -> &mut U>(orig: Self, f: F) -> SRefMut<'b, U> {
+        let orig = std::mem::ManuallyDrop::new(orig);
+        let value = orig.value;
+
+        SRefMut {
+            value: f(unsafe { &mut *value }),
+            borrow: orig.borrow,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Deref for SRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.value }
+    }
+}
+
+impl<T> Drop for SRef<'_, T> {
+    fn drop(&mut self) {
+        self.borrow.set(self.borrow.get() - 1);
+    }
+}
+
+impl<T> Deref for SRefMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.value }
+    }
+}
+
+impl<T> DerefMut for SRefMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.value }
+    }
+}
+
+impl<T> Drop for SRefMut<'_, T> {
+    fn drop(&mut self) {
+        self.borrow.set(UNUSED);
+    }
+}
+
+impl<T: Debug> Debug for SRef<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Display> Display for SRef<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<T: Debug> Debug for SRefMut<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Display> Display for SRefMut<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl SBorrowError {
+    pub const fn new() -> Self {
+        Self { _private: 0 }
+    }
+}
+
+impl SBorrowMutError {
+    pub const fn new() -> Self {
+        Self { _private: 0 }
+    }
+}
+
+impl Display for SBorrowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("already mutably borrowed")
+    }
+}
+
+impl Display for SBorrowMutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("already borrowed")
+    }
+}
+
+impl Error for SBorrowError {}
+impl Error for SBorrowMutError {}
+
+impl From<BorrowError> for SBorrowError {
+    fn from(_: BorrowError) -> Self {
+        Self::new()
+    }
+}
+
+impl From<BorrowMutError> for SBorrowMutError {
+    fn from(_: BorrowMutError) -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for SRefCell<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.borrow().clone())
+    }
+}
+
+impl<T: Default> Default for SRefCell<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: PartialEq> PartialEq for SRefCell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.borrow() == *other.borrow()
+    }
+}
+
+impl<T: Eq> Eq for SRefCell<T> {}
+
+impl<T: Debug> Debug for SRefCell<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_struct("SRefCell");
+        match self.try_borrow() {
+            SResult::Ok(borrow) => d.field("value", &borrow),
+            SResult::Err(_) => d.field("value", &format_args!("<borrowed>")),
+        };
+        d.finish()
+    }
+}
+
+impl<T> From<T> for SRefCell<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<RefCell<T>> for SRefCell<T> {
+    fn from(cell: RefCell<T>) -> Self {
+        Self::from_refcell(cell)
+    }
+}
+
+impl<T> From<SRefCell<T>> for RefCell<T> {
+    fn from(cell: SRefCell<T>) -> Self {
+        cell.into_refcell()
+    }
+}
+
+impl<T: IntoSafe> IntoSafe for RefCell<T> {
+    type Safe = SRefCell<T::Safe>;
+
+    fn into_safe(self) -> Self::Safe {
+        SRefCell::new(self.into_inner().into_safe())
+    }
+}
+impl<T: FromSafe> FromSafe for RefCell<T> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        RefCell::new(T::from_safe(safe.into_inner()))
+    }
+}
@@ -1,43 +1,660 @@
-use super::prelude::SString;
+use super::{
+    boxed::SBox,
+    option::SOption,
+    prelude::{SResult, SString},
+};
+use crate::{type_tag::STypeTag, FromSafe, IntoSafe};
 use std::{
+    backtrace::{Backtrace, BacktraceStatus},
     error::Error,
     fmt::{Debug, Display},
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::Deref,
 };
 
+/// `SResult` with an [`SDynError`], for functions that can fail with any error
+pub type SAnyResult<T> = SResult<T, SDynError>;
+
 /// FFI-safe equivalent of `Box<dyn Error + Send + Sync>`
+///
+/// Can be either a snapshot of an error ([`SDynError::from_error`]), or own the
+/// original error behind an FFI-safe vtable ([`SDynError::new`]). The latter
+/// can be downcast back to the original type, but only in the binary that created it.
+/// Anywhere else it behaves like a snapshot.
+///
+/// Like `anyhow::Error`, this type does not implement `Error` itself (so that any error
+/// can be converted into it with `?`), but it dereferences to `dyn Error + Send + Sync`.
 #[repr(C)]
 pub struct SDynError {
-    // this implementation barely resembles the real dyn Error
-    // and is lossy (information is lost when you convert a dyn Error to this type)
-    // but I couldn't put myself through the horrors of making an accurate type.
-    // Dynamic dispatch + FFI = not a good time
-    // Feel free to take on this if you want to
-    debug: SString,
-    display: SString,
+    // Boxed so that `Result<T, SDynError>` stays small
+    node: SBox<SErrorNode>,
 }
 
-impl SDynError {
-    pub fn from_error(e: &dyn Error) -> Self {
+#[repr(C)]
+struct SErrorNode {
+    repr: SErrorRepr,
+    // Only known for errors whose type was known statically, see `SDynError::type_name`
+    type_name: SOption<SString>,
+    // Every error in the `source()` chain is kept in order, each with its
+    // display and debug representation, so nothing is lost on the far side.
+    source: SOption<SDynError>,
+    backtrace: SOption<SString>,
+}
+
+#[repr(C)]
+enum SErrorRepr {
+    Snapshot { display: SString, debug: SString },
+    Object(SErrorObject),
+}
+
+#[repr(C)]
+struct SErrorObject {
+    ptr: *mut (),
+    vtable: &'static SErrorVTable,
+}
+
+#[repr(C)]
+struct SErrorVTable {
+    display: unsafe extern "C" fn(*const ()) -> SString,
+    debug: unsafe extern "C" fn(*const ()) -> SString,
+    source: unsafe extern "C" fn(*const ()) -> SOption<SDynError>,
+    drop: unsafe extern "C" fn(*mut ()),
+    type_tag: unsafe extern "C" fn() -> STypeTag,
+}
+
+struct VTableOf<E>(PhantomData<E>);
+
+impl<E: Error + Send + Sync + 'static> VTableOf<E> {
+    const VTABLE: SErrorVTable = SErrorVTable {
+        display: Self::display,
+        debug: Self::debug,
+        source: Self::source,
+        drop: Self::drop,
+        type_tag: Self::type_tag,
+    };
+
+    unsafe extern "C" fn display(ptr: *const ()) -> SString {
+        format!("{}", unsafe { &*(ptr as *const E) }).into()
+    }
+    unsafe extern "C" fn debug(ptr: *const ()) -> SString {
+        format!("{:?}", unsafe { &*(ptr as *const E) }).into()
+    }
+    unsafe extern "C" fn source(ptr: *const ()) -> SOption<SDynError> {
+        SDynError::sources_of(unsafe { &*(ptr as *const E) })
+    }
+    unsafe extern "C" fn drop(ptr: *mut ()) {
+        unsafe { drop(Box::from_raw(ptr as *mut E)) }
+    }
+    unsafe extern "C" fn type_tag() -> STypeTag {
+        STypeTag::of::<E>()
+    }
+}
+
+/// Lets a `Box<dyn Error>` be stored behind the vtable, while still
+/// being able to downcast to what's inside
+struct BoxedError(Box<dyn Error + Send + Sync>);
+
+impl Debug for BoxedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for BoxedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for BoxedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+/// Implements `Error` for a node of the `SDynError` chain
+#[repr(transparent)]
+struct ErrorImpl(SDynError);
+
+impl ErrorImpl {
+    fn from_ref(e: &SDynError) -> &Self {
+        unsafe { &*(e as *const SDynError as *const ErrorImpl) }
+    }
+}
+
+impl Debug for ErrorImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for ErrorImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for ErrorImpl {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0
+            .node
+            .source
+            .as_ref()
+            .map(|s| ErrorImpl::from_ref(s) as &(dyn Error + 'static))
+    }
+}
+
+impl SErrorObject {
+    fn new<E: Error + Send + Sync + 'static>(e: E) -> Self {
         Self {
-            debug: format!("{:?}", e).into(),
-            display: format!("{}", e).into(),
+            ptr: Box::into_raw(Box::new(e)) as *mut (),
+            vtable: &VTableOf::<E>::VTABLE,
         }
     }
+    fn is<E: 'static>(&self) -> bool {
+        unsafe { (self.vtable.type_tag)() == STypeTag::of::<E>() }
+    }
+    fn into_raw(self) -> *mut () {
+        ManuallyDrop::new(self).ptr
+    }
+}
+
+impl Drop for SErrorObject {
+    fn drop(&mut self) {
+        unsafe { (self.vtable.drop)(self.ptr) }
+    }
+}
+
+unsafe impl Send for SErrorObject {}
+unsafe impl Sync for SErrorObject {}
+
+impl SDynError {
+    /// Takes ownership of the error, so that it can be downcast later.
+    ///
+    /// The `source()` chain and a backtrace (if enabled with the `RUST_BACKTRACE` or
+    /// `RUST_LIB_BACKTRACE` environment variables, see [`Backtrace::capture`])
+    /// are captured immediately.
+    pub fn new<E: Error + Send + Sync + 'static>(e: E) -> Self {
+        Self::from_object(SErrorObject::new(e), Some(std::any::type_name::<E>()))
+    }
+    /// Takes ownership of a boxed error, see [`SDynError::new`]
+    pub fn from_boxed(e: Box<dyn Error + Send + Sync>) -> Self {
+        match e.downcast::<ErrorImpl>() {
+            Ok(e) => e.0,
+            Err(e) => Self::from_object(SErrorObject::new(BoxedError(e)), None),
+        }
+    }
+    /// Captures a snapshot of the error and its whole `source()` chain.
+    ///
+    /// A backtrace is also captured if enabled, see [`SDynError::new`].
+    pub fn from_error<E: Error + ?Sized>(e: &E) -> Self {
+        let type_name = std::any::type_name::<E>();
+        // the type of a trait object is not known here
+        let type_name = (!type_name.starts_with("dyn ")).then_some(type_name);
+
+        let mut error = Self::snapshot(e, type_name);
+        error.node.backtrace = capture_backtrace();
+
+        error
+    }
+    /// Returns the original boxed error if possible, and a boxed `SDynError` otherwise.
     pub fn into_dyn_error(self) -> Box<dyn Error + Send + Sync> {
-        Box::new(self)
+        match self.downcast_top::<BoxedError>() {
+            Ok(e) => e.0,
+            Err(e) => Box::new(ErrorImpl(e)),
+        }
+    }
+    /// Wraps this error with a message, like `anyhow::Error::context`.
+    ///
+    /// The backtrace, if any, is moved to the new outermost error.
+    pub fn context<C: Display + Send + Sync + 'static>(mut self, context: C) -> Self {
+        let backtrace = std::mem::replace(&mut self.node.backtrace, SOption::None);
+        let message = context.to_string();
+
+        Self::from_node(SErrorNode {
+            repr: SErrorRepr::Snapshot {
+                display: message.clone().into(),
+                debug: message.into(),
+            },
+            type_name: SOption::None,
+            source: SOption::Some(self),
+            backtrace,
+        })
+    }
+    /// The type name of the original error, if it was known.
+    ///
+    /// It is recorded for errors converted from a concrete type, with [`SDynError::new`],
+    /// [`SDynError::from_error`] or `From`. Sources are only reachable as `dyn Error`
+    /// through [`Error::source`], whose type name can't be recovered, so they report
+    /// `None` unless they were converted to an `SDynError` themselves. Messages
+    /// ([`SDynError::from_message`], [`SDynError::context`]) and boxed errors report
+    /// `None` as well.
+    pub fn type_name(&self) -> Option<&str> {
+        self.node.type_name.as_ref().map(|s| s.as_str().into_str())
+    }
+    /// The backtrace captured when this error was created, if any
+    pub fn backtrace(&self) -> Option<&str> {
+        self.node.backtrace.as_ref().map(|s| s.as_str().into_str())
+    }
+    /// Iterates over this error and all of its sources
+    pub fn chain(&self) -> impl Iterator<Item = &SDynError> {
+        std::iter::successors(Some(self), |e| e.node.source.as_ref())
+    }
+    /// Returns `true` if this error or any of its sources is of type `E`.
+    ///
+    /// Snapshots and errors created in other binaries never match.
+    pub fn is<E: Error + 'static>(&self) -> bool {
+        self.downcast_ref::<E>().is_some()
+    }
+    /// Returns a reference to the first error in the chain that is of type `E`.
+    ///
+    /// Snapshots and errors created in other binaries never match.
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        self.chain().find_map(|e| e.downcast_top_ref())
+    }
+    /// Returns a mutable reference to the first error in the chain that is of type `E`.
+    ///
+    /// Snapshots and errors created in other binaries never match.
+    pub fn downcast_mut<E: Error + 'static>(&mut self) -> Option<&mut E> {
+        if self.downcast_top_ref::<E>().is_some() {
+            return self.downcast_top_mut();
+        }
+
+        match &mut self.node.source {
+            SOption::Some(source) => source.downcast_mut(),
+            SOption::None => None,
+        }
+    }
+    /// Recovers the first error in the chain that is of type `E`.
+    ///
+    /// Errors wrapping it (for example added with [`SDynError::context`]) are dropped.
+    pub fn downcast<E: Error + 'static>(mut self) -> Result<E, Self> {
+        if self.downcast_top_ref::<E>().is_some() {
+            return self.downcast_top();
+        }
+
+        match std::mem::replace(&mut self.node.source, SOption::None) {
+            SOption::Some(source) => match source.downcast() {
+                Ok(e) => Ok(e),
+                Err(source) => {
+                    self.node.source = SOption::Some(source);
+                    Err(self)
+                }
+            },
+            SOption::None => Err(self),
+        }
+    }
+
+    fn downcast_top_ref<E: Error + 'static>(&self) -> Option<&E> {
+        let SErrorRepr::Object(object) = &self.node.repr else {
+            return None;
+        };
+
+        if object.is::<E>() {
+            Some(unsafe { &*(object.ptr as *const E) })
+        } else if object.is::<BoxedError>() {
+            unsafe { &*(object.ptr as *const BoxedError) }
+                .0
+                .downcast_ref()
+        } else {
+            None
+        }
+    }
+    fn downcast_top_mut<E: Error + 'static>(&mut self) -> Option<&mut E> {
+        let SErrorRepr::Object(object) = &mut self.node.repr else {
+            return None;
+        };
+
+        if object.is::<E>() {
+            Some(unsafe { &mut *(object.ptr as *mut E) })
+        } else if object.is::<BoxedError>() {
+            unsafe { &mut *(object.ptr as *mut BoxedError) }
+                .0
+                .downcast_mut()
+        } else {
+            None
+        }
+    }
+    fn downcast_top<E: Error + 'static>(self) -> Result<E, Self> {
+        if self.downcast_top_ref::<E>().is_none() {
+            return Err(self);
+        }
+        let SErrorRepr::Object(object) = self.node.into_box().repr else {
+            unreachable!()
+        };
+
+        if object.is::<E>() {
+            Ok(*unsafe { Box::from_raw(object.into_raw() as *mut E) })
+        } else {
+            let boxed = unsafe { Box::from_raw(object.into_raw() as *mut BoxedError) };
+            match boxed.0.downcast() {
+                Ok(e) => Ok(*e),
+                Err(_) => unreachable!(),
+            }
+        }
+    }
+
+    /// An error with only a message, like `anyhow::anyhow!`
+    pub fn from_message<M: Display + Send + Sync + 'static>(message: M) -> Self {
+        let message = message.to_string();
+
+        Self::from_node(SErrorNode {
+            repr: SErrorRepr::Snapshot {
+                display: message.clone().into(),
+                debug: format!("{:?}", message).into(),
+            },
+            type_name: SOption::None,
+            source: SOption::None,
+            backtrace: capture_backtrace(),
+        })
+    }
+
+    fn from_node(node: SErrorNode) -> Self {
+        Self {
+            node: SBox::from_box(Box::new(node)),
+        }
+    }
+
+    fn from_object(object: SErrorObject, type_name: Option<&str>) -> Self {
+        let source = unsafe { (object.vtable.source)(object.ptr) };
+
+        Self::from_node(SErrorNode {
+            repr: SErrorRepr::Object(object),
+            type_name: SOption::from_option(type_name.map(|t| t.to_owned().into())),
+            source,
+            backtrace: capture_backtrace(),
+        })
+    }
+    fn snapshot<E: Error + ?Sized>(e: &E, type_name: Option<&str>) -> Self {
+        Self::from_node(SErrorNode {
+            repr: SErrorRepr::Snapshot {
+                display: format!("{}", e).into(),
+                debug: format!("{:?}", e).into(),
+            },
+            type_name: SOption::from_option(type_name.map(|t| t.to_owned().into())),
+            source: Self::sources_of(e),
+            backtrace: SOption::None,
+        })
+    }
+    fn sources_of<E: Error + ?Sized>(e: &E) -> SOption<SDynError> {
+        SOption::from_option(e.source().map(Self::from_source))
+    }
+    fn from_source(e: &(dyn Error + 'static)) -> Self {
+        // keep the type names of errors that already went through this conversion
+        let type_name = e.downcast_ref::<ErrorImpl>().and_then(|e| e.0.type_name());
+
+        Self::snapshot(e, type_name)
+    }
+}
+
+fn capture_backtrace() -> SOption<SString> {
+    let backtrace = Backtrace::capture();
+
+    if backtrace.status() == BacktraceStatus::Captured {
+        SOption::Some(backtrace.to_string().into())
+    } else {
+        SOption::None
     }
 }
 
 impl Display for SDynError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display)
+        match &self.node.repr {
+            SErrorRepr::Snapshot { display, .. } => write!(f, "{}", display),
+            SErrorRepr::Object(o) => write!(f, "{}", unsafe { (o.vtable.display)(o.ptr) }),
+        }
     }
 }
 
 impl Debug for SDynError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.debug)
+        match &self.node.repr {
+            SErrorRepr::Snapshot { debug, .. } => write!(f, "{}", debug),
+            SErrorRepr::Object(o) => write!(f, "{}", unsafe { (o.vtable.debug)(o.ptr) }),
+        }
+    }
+}
+
+impl Deref for SDynError {
+    type Target = dyn Error + Send + Sync + 'static;
+
+    fn deref(&self) -> &Self::Target {
+        ErrorImpl::from_ref(self)
+    }
+}
+
+impl AsRef<dyn Error + Send + Sync + 'static> for SDynError {
+    fn as_ref(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &**self
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<E> for SDynError {
+    fn from(e: E) -> Self {
+        Self::new(e)
+    }
+}
+
+impl From<SDynError> for Box<dyn Error + Send + Sync + 'static> {
+    fn from(e: SDynError) -> Self {
+        e.into_dyn_error()
+    }
+}
+
+/// Adds context to errors, converting them to [`SDynError`].
+///
+/// Like `anyhow::Context`. A std `Result` stays a `Result`, so that it can be used
+/// with `?`. An [`SResult`] or [`SOption`] becomes an [`SAnyResult`], so that it can
+/// be returned across the FFI boundary as is.
+pub trait Context<T> {
+    /// `Self` with the error replaced by an [`SDynError`]
+    type Output;
+
+    /// Wraps the error with a message
+    fn context<C: Display + Send + Sync + 'static>(self, context: C) -> Self::Output;
+    /// Wraps the error with a lazily evaluated message
+    fn with_context<C, F>(self, f: F) -> Self::Output
+    where
+        C: Display + Send + Sync + 'static,
+        F: FnOnce() -> C;
+}
+
+impl<T, E: Into<SDynError>> Context<T> for Result<T, E> {
+    type Output = Result<T, SDynError>;
+
+    fn context<C: Display + Send + Sync + 'static>(self, context: C) -> Self::Output {
+        self.with_context(|| context)
+    }
+    fn with_context<C, F>(self, f: F) -> Self::Output
+    where
+        C: Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|e| e.into().context(f()))
+    }
+}
+
+impl<T, E: Into<SDynError>> Context<T> for SResult<T, E> {
+    type Output = SAnyResult<T>;
+
+    fn context<C: Display + Send + Sync + 'static>(self, context: C) -> Self::Output {
+        self.into_result().context(context).into()
+    }
+    fn with_context<C, F>(self, f: F) -> Self::Output
+    where
+        C: Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        self.into_result().with_context(f).into()
+    }
+}
+
+impl<T> Context<T> for SOption<T> {
+    type Output = SAnyResult<T>;
+
+    fn context<C: Display + Send + Sync + 'static>(self, context: C) -> Self::Output {
+        self.with_context(|| context)
+    }
+    fn with_context<C, F>(self, f: F) -> Self::Output
+    where
+        C: Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        match self {
+            SOption::Some(v) => SResult::Ok(v),
+            SOption::None => SResult::Err(SDynError::from_message(f())),
+        }
     }
 }
 
-impl Error for SDynError {}
+impl IntoSafe for Box<dyn Error + Send + Sync> {
+    type Safe = SDynError;
+
+    fn into_safe(self) -> Self::Safe {
+        SDynError::from_boxed(self)
+    }
+}
+impl FromSafe for Box<dyn Error + Send + Sync> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_dyn_error()
+    }
+}
+
+/// Lets an `anyhow::Error` be stored behind the vtable
+#[cfg(feature = "anyhow")]
+struct AnyhowError(anyhow::Error);
+
+#[cfg(feature = "anyhow")]
+impl Debug for AnyhowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl Display for AnyhowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl Error for AnyhowError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl SDynError {
+    /// Converts an `anyhow::Error`, keeping every `.context()` layer and its backtrace
+    pub fn from_anyhow(e: anyhow::Error) -> Self {
+        let backtrace = match e.backtrace().status() {
+            BacktraceStatus::Captured => SOption::Some(e.backtrace().to_string().into()),
+            _ => SOption::None,
+        };
+
+        let mut error = Self::from_object(SErrorObject::new(AnyhowError(e)), None);
+        error.node.backtrace = backtrace;
+
+        error
+    }
+    /// Returns the original `anyhow::Error` if possible,
+    /// and an `anyhow::Error` wrapping this error otherwise.
+    ///
+    /// Either way, its chain prints the same.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self.downcast_top::<AnyhowError>() {
+            Ok(e) => e.0,
+            Err(e) => anyhow::Error::new(ErrorImpl(e)),
+        }
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl IntoSafe for anyhow::Error {
+    type Safe = SDynError;
+
+    fn into_safe(self) -> Self::Safe {
+        SDynError::from_anyhow(self)
+    }
+}
+#[cfg(feature = "anyhow")]
+impl FromSafe for anyhow::Error {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_anyhow()
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl From<SDynError> for anyhow::Error {
+    fn from(e: SDynError) -> Self {
+        e.into_anyhow()
+    }
+}
+
+/// Lets an `eyre::Report` be stored behind the vtable
+#[cfg(feature = "eyre")]
+struct EyreReport(eyre::Report);
+
+#[cfg(feature = "eyre")]
+impl Debug for EyreReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl Display for EyreReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl Error for EyreReport {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl SDynError {
+    /// Converts an `eyre::Report`, keeping every `.wrap_err()` layer
+    pub fn from_eyre(e: eyre::Report) -> Self {
+        Self::from_object(SErrorObject::new(EyreReport(e)), None)
+    }
+    /// Returns the original `eyre::Report` if possible,
+    /// and an `eyre::Report` wrapping this error otherwise.
+    ///
+    /// Either way, its chain prints the same.
+    pub fn into_eyre(self) -> eyre::Report {
+        match self.downcast_top::<EyreReport>() {
+            Ok(e) => e.0,
+            Err(e) => eyre::Report::new(ErrorImpl(e)),
+        }
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl IntoSafe for eyre::Report {
+    type Safe = SDynError;
+
+    fn into_safe(self) -> Self::Safe {
+        SDynError::from_eyre(self)
+    }
+}
+#[cfg(feature = "eyre")]
+impl FromSafe for eyre::Report {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_eyre()
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl From<SDynError> for eyre::Report {
+    fn from(e: SDynError) -> Self {
+        e.into_eyre()
+    }
+}
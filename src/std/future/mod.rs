@@ -0,0 +1,35 @@
+mod sfuture;
+mod slocalfuture;
+mod sstream;
+
+pub use sfuture::SFuture;
+pub use slocalfuture::SLocalFuture;
+pub use sstream::SStream;
+
+use std::future::Future;
+
+/// Conversion of any future into an FFI-safe boxed future.
+pub trait FutureExt: Future + Sized + 'static {
+    fn into_sfuture(self) -> SFuture<Self::Output>
+    where
+        Self: Send,
+    {
+        SFuture::new(self)
+    }
+    fn into_slocal_future(self) -> SLocalFuture<Self::Output> {
+        SLocalFuture::new(self)
+    }
+}
+
+impl<F: Future + 'static> FutureExt for F {}
+
+/// Conversion of any stream into an FFI-safe boxed stream.
+#[cfg(feature = "futures")]
+pub trait StreamExt: futures_core::Stream + Sized + Send + 'static {
+    fn into_sstream(self) -> SStream<Self::Item> {
+        SStream::new(self)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<S: futures_core::Stream + Send + 'static> StreamExt for S {}
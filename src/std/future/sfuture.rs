@@ -0,0 +1,124 @@
+use crate::std::task::{SContext, SPoll};
+use std::{
+    fmt::Debug,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A boxed `Send` future that can be polled from any binary.
+///
+/// See documentation of [`std::future::Future`]
+///
+/// The future is polled and dropped by function pointers of the binary that created it.
+/// A panic while polling can't unwind across the boundary and aborts the process.
+///
+/// Like most futures, polling it again after it completed panics. The panic
+/// happens in the binary that polls it, so it can be caught there.
+#[repr(C)]
+pub struct SFuture<T> {
+    ptr: *mut (),
+    /// A pointer to a static, `&'static` would require `T: 'static`
+    vtable: *const SFutureVTable<T>,
+    completed: bool,
+}
+
+#[repr(C)]
+pub(super) struct SFutureVTable<T> {
+    pub(super) poll: unsafe extern "C" fn(*mut (), SContext) -> SPoll<T>,
+    pub(super) drop: unsafe extern "C" fn(*mut ()),
+}
+
+pub(super) struct VTableOf<F>(PhantomData<F>);
+
+impl<F: Future> VTableOf<F> {
+    pub(super) const VTABLE: SFutureVTable<F::Output> = SFutureVTable {
+        poll: Self::poll,
+        drop: Self::drop,
+    };
+
+    /// The future is dropped as soon as it completes. Callers never poll it again,
+    /// so the `Option` only keeps a completed `async` block from being resumed.
+    pub(super) fn into_raw(future: F) -> *mut () {
+        Box::into_raw(Box::new(Some(future))) as *mut ()
+    }
+
+    unsafe extern "C" fn poll(ptr: *mut (), mut cx: SContext) -> SPoll<F::Output> {
+        let slot = unsafe { &mut *(ptr as *mut Option<F>) };
+        let Some(future) = slot.as_mut() else {
+            // the caller checks this before polling, and a panic can't unwind from here
+            std::process::abort();
+        };
+        // the future is boxed, so it never moves
+        let future = unsafe { Pin::new_unchecked(future) };
+
+        let poll = cx.with_context(|cx| future.poll(cx));
+        if poll.is_ready() {
+            *slot = None;
+        }
+
+        SPoll::from_poll(poll)
+    }
+    unsafe extern "C" fn drop(ptr: *mut ()) {
+        std::mem::drop(unsafe { Box::from_raw(ptr as *mut Option<F>) })
+    }
+}
+
+/// Polls a future behind a vtable, panicking in the caller's binary if it already completed
+pub(super) fn poll_safe<T>(
+    ptr: *mut (),
+    vtable: &SFutureVTable<T>,
+    completed: &mut bool,
+    cx: SContext,
+) -> SPoll<T> {
+    if *completed {
+        panic!("future polled after completion");
+    }
+
+    let poll = unsafe { (vtable.poll)(ptr, cx) };
+    *completed = matches!(poll, SPoll::Ready(_));
+
+    poll
+}
+
+impl<T> SFuture<T> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = T> + Send + 'static,
+    {
+        Self {
+            ptr: VTableOf::<F>::into_raw(future),
+            vtable: &VTableOf::<F>::VTABLE,
+            completed: false,
+        }
+    }
+    /// Polls the future with an FFI-safe context, panics if it already completed.
+    pub fn poll_safe(&mut self, cx: SContext) -> SPoll<T> {
+        poll_safe(self.ptr, unsafe { &*self.vtable }, &mut self.completed, cx)
+    }
+}
+
+impl<T> Future for SFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        SContext::from_context(cx, |scx| this.poll_safe(scx)).into_poll()
+    }
+}
+
+impl<T> Drop for SFuture<T> {
+    fn drop(&mut self) {
+        unsafe { ((*self.vtable).drop)(self.ptr) }
+    }
+}
+
+unsafe impl<T> Send for SFuture<T> {}
+
+impl<T> Debug for SFuture<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SFuture").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,57 @@
+use super::sfuture::{poll_safe, SFutureVTable, VTableOf};
+use crate::std::task::{SContext, SPoll};
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A boxed future that can be polled from any binary, but only on the thread that created it.
+///
+/// Same as [`SFuture`](super::SFuture), but without the `Send` requirement.
+#[repr(C)]
+pub struct SLocalFuture<T> {
+    ptr: *mut (),
+    vtable: *const SFutureVTable<T>,
+    completed: bool,
+}
+
+impl<T> SLocalFuture<T> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = T> + 'static,
+    {
+        Self {
+            ptr: VTableOf::<F>::into_raw(future),
+            vtable: &VTableOf::<F>::VTABLE,
+            completed: false,
+        }
+    }
+    /// Polls the future with an FFI-safe context, panics if it already completed.
+    pub fn poll_safe(&mut self, cx: SContext) -> SPoll<T> {
+        poll_safe(self.ptr, unsafe { &*self.vtable }, &mut self.completed, cx)
+    }
+}
+
+impl<T> Future for SLocalFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        SContext::from_context(cx, |scx| this.poll_safe(scx)).into_poll()
+    }
+}
+
+impl<T> Drop for SLocalFuture<T> {
+    fn drop(&mut self) {
+        unsafe { ((*self.vtable).drop)(self.ptr) }
+    }
+}
+
+impl<T> Debug for SLocalFuture<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SLocalFuture").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    std::{
+        option::SOption,
+        task::{SContext, SPoll},
+    },
+    STuple2,
+};
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A boxed `Send` stream of values that can be polled from any binary.
+///
+/// See documentation of [`futures::Stream`](https://docs.rs/futures/latest/futures/stream/trait.Stream.html)
+///
+/// The stream is polled and dropped by function pointers of the binary that created it.
+/// A panic while polling can't unwind across the boundary and aborts the process.
+///
+/// The stream is fused: it's dropped once it ends, and polling it again returns `None`.
+///
+/// Without the `futures` feature, it can be created from a closure with
+/// [`SStream::from_poll_fn`], but not used as a `Stream`.
+#[repr(C)]
+pub struct SStream<T> {
+    ptr: *mut (),
+    /// A pointer to a static, `&'static` would require `T: 'static`
+    vtable: *const SStreamVTable<T>,
+}
+
+#[repr(C)]
+struct SStreamVTable<T> {
+    poll_next: unsafe extern "C" fn(*mut (), SContext) -> SPoll<SOption<T>>,
+    size_hint: unsafe extern "C" fn(*mut ()) -> STuple2<usize, SOption<usize>>,
+    drop: unsafe extern "C" fn(*mut ()),
+}
+
+/// What the vtable needs from a stream, so that streams can be
+/// created without the `futures` feature
+trait PollNext {
+    type Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+    fn size_hint(&self) -> (usize, Option<usize>);
+}
+
+#[cfg(feature = "futures")]
+impl<S: futures_core::Stream> PollNext for S {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        futures_core::Stream::poll_next(self, cx)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        futures_core::Stream::size_hint(self)
+    }
+}
+
+struct PollFn<F>(F);
+
+impl<T, F: FnMut(&mut Context<'_>) -> Poll<Option<T>>> PollNext for PollFn<F> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        // the closure is never pinned
+        (unsafe { &mut self.get_unchecked_mut().0 })(cx)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+struct VTableOf<S>(PhantomData<S>);
+
+impl<S: PollNext> VTableOf<S> {
+    const VTABLE: SStreamVTable<S::Item> = SStreamVTable {
+        poll_next: Self::poll_next,
+        size_hint: Self::size_hint,
+        drop: Self::drop,
+    };
+
+    /// The stream is dropped as soon as it ends, so that polling it again
+    /// can't panic inside an `extern "C"` function
+    fn into_raw(stream: S) -> *mut () {
+        Box::into_raw(Box::new(Some(stream))) as *mut ()
+    }
+
+    unsafe extern "C" fn poll_next(ptr: *mut (), mut cx: SContext) -> SPoll<SOption<S::Item>> {
+        let slot = unsafe { &mut *(ptr as *mut Option<S>) };
+        let Some(stream) = slot.as_mut() else {
+            return SPoll::Ready(SOption::None);
+        };
+        // the stream is boxed, so it never moves
+        let stream = unsafe { Pin::new_unchecked(stream) };
+
+        match cx.with_context(|cx| stream.poll_next(cx)) {
+            Poll::Ready(Some(item)) => SPoll::Ready(SOption::Some(item)),
+            Poll::Ready(None) => {
+                *slot = None;
+                SPoll::Ready(SOption::None)
+            }
+            Poll::Pending => SPoll::Pending,
+        }
+    }
+    unsafe extern "C" fn size_hint(ptr: *mut ()) -> STuple2<usize, SOption<usize>> {
+        let (lower, upper) = match unsafe { &*(ptr as *const Option<S>) } {
+            Some(stream) => stream.size_hint(),
+            None => (0, Some(0)),
+        };
+
+        STuple2(lower, SOption::from_option(upper))
+    }
+    unsafe extern "C" fn drop(ptr: *mut ()) {
+        std::mem::drop(unsafe { Box::from_raw(ptr as *mut Option<S>) })
+    }
+}
+
+impl<T> SStream<T> {
+    #[cfg(feature = "futures")]
+    pub fn new<S>(stream: S) -> Self
+    where
+        S: futures_core::Stream<Item = T> + Send + 'static,
+    {
+        Self::from_poll_next(stream)
+    }
+    /// Creates a stream that calls the closure to poll for the next value,
+    /// like `futures::stream::poll_fn`.
+    ///
+    /// Its [`size_hint`](Self::size_hint) is always `(0, None)`.
+    pub fn from_poll_fn<F>(f: F) -> Self
+    where
+        F: FnMut(&mut Context<'_>) -> Poll<Option<T>> + Send + 'static,
+    {
+        Self::from_poll_next(PollFn(f))
+    }
+    fn from_poll_next<S>(stream: S) -> Self
+    where
+        S: PollNext<Item = T> + Send + 'static,
+    {
+        Self {
+            ptr: VTableOf::<S>::into_raw(stream),
+            vtable: &VTableOf::<S>::VTABLE,
+        }
+    }
+    /// Attempts to pull out the next value of this stream.
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        SContext::from_context(cx, |scx| self.poll_next_safe(scx))
+            .into_poll()
+            .map(SOption::into_option)
+    }
+    /// Same as [`poll_next`](Self::poll_next), but with an FFI-safe context.
+    pub fn poll_next_safe(&mut self, cx: SContext) -> SPoll<SOption<T>> {
+        unsafe { ((*self.vtable).poll_next)(self.ptr, cx) }
+    }
+    /// Returns the bounds on the remaining length of the stream.
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        let STuple2(lower, upper) = unsafe { ((*self.vtable).size_hint)(self.ptr) };
+
+        (lower, upper.into_option())
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> futures_core::Stream for SStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        SStream::poll_next(self.get_mut(), cx)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        SStream::size_hint(self)
+    }
+}
+
+impl<T> Drop for SStream<T> {
+    fn drop(&mut self) {
+        unsafe { ((*self.vtable).drop)(self.ptr) }
+    }
+}
+
+unsafe impl<T> Send for SStream<T> {}
+
+impl<T> Debug for SStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SStream").finish_non_exhaustive()
+    }
+}
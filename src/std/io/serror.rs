@@ -1,8 +1,11 @@
 use super::SErrorKind;
-use crate::std::{error::SDynError, prelude::SOption};
+use crate::{
+    std::{error::SDynError, prelude::SString},
+    FromSafe, IntoSafe,
+};
 use std::{
     fmt::{Debug, Display},
-    io::Error,
+    io::{Error, ErrorKind},
 };
 
 /// The error type for I/O operations of the Read, Write, Seek, and associated traits.
@@ -16,40 +19,199 @@ pub struct SError {
 #[repr(C)]
 enum SRepr {
     Os(i32),
-    Custom {
-        kind: SErrorKind,
-        error: SOption<SDynError>,
-    },
+    Simple(SErrorKind),
+    SimpleMessage { kind: SErrorKind, message: SString },
+    Custom { kind: SErrorKind, error: SDynError },
 }
 
 impl SError {
     pub fn from_error(e: Error) -> Self {
         if let Some(os_err) = e.raw_os_error() {
+            return Self::from_raw_os_error(os_err);
+        }
+
+        let kind = SErrorKind::from_errorkind(&e.kind());
+
+        if e.get_ref().is_some() {
+            let error = SDynError::from_boxed(e.into_inner().unwrap());
+
+            return Self {
+                repr: SRepr::Custom { kind, error },
+            };
+        }
+
+        // std keeps a static message for some of its own errors,
+        // which can only be told apart from a plain kind by its output
+        let message = e.to_string();
+        if message == Error::from(e.kind()).to_string() {
             Self {
-                repr: SRepr::Os(os_err),
+                repr: SRepr::Simple(kind),
             }
         } else {
             Self {
-                repr: SRepr::Custom {
-                    kind: SErrorKind::from_errorkind(&e.kind()),
-                    error: SOption::from_option(e.into_inner().map(|e| SDynError::from_error(&*e))),
+                repr: SRepr::SimpleMessage {
+                    kind,
+                    message: message.into(),
                 },
             }
         }
     }
+    /// Note that std can't create an error with a non-static message, so
+    /// an error with a message (but no payload) becomes one with a `String` payload.
     pub fn into_error(self) -> Error {
         match self.repr {
             SRepr::Os(os_err) => Error::from_raw_os_error(os_err),
+            SRepr::Simple(kind) => kind.as_errorkind().into(),
+            SRepr::SimpleMessage { kind, message } => {
+                Error::new(kind.as_errorkind(), message.into_string())
+            }
             SRepr::Custom { kind, error } => {
-                if let Some(error) = error.into_option() {
-                    Error::new(kind.as_errorkind(), error)
-                } else {
-                    kind.as_errorkind().into()
-                }
+                Error::new(kind.as_errorkind(), error.into_dyn_error())
             }
         }
     }
-    // TODO other methods?
+    /// Creates a new I/O error from a known kind of error as well as an arbitrary error payload.
+    ///
+    /// See documentation of [`std::io::Error::new`]
+    pub fn new<E>(kind: SErrorKind, error: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self {
+            repr: SRepr::Custom {
+                kind,
+                error: SDynError::from_boxed(error.into()),
+            },
+        }
+    }
+    /// Creates a new I/O error from an arbitrary error payload, with kind [`SErrorKind::Other`].
+    ///
+    /// See documentation of [`std::io::Error::other`]
+    pub fn other<E>(error: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self::new(SErrorKind::Other, error)
+    }
+    /// See documentation of [`std::io::Error::from_raw_os_error`]
+    pub fn from_raw_os_error(code: i32) -> Self {
+        Self {
+            repr: SRepr::Os(code),
+        }
+    }
+    /// See documentation of [`std::io::Error::last_os_error`]
+    pub fn last_os_error() -> Self {
+        Self::from_error(Error::last_os_error())
+    }
+    /// See documentation of [`std::io::Error::raw_os_error`]
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self.repr {
+            SRepr::Os(code) => Some(code),
+            _ => None,
+        }
+    }
+    /// See documentation of [`std::io::Error::kind`]
+    pub fn kind(&self) -> SErrorKind {
+        match &self.repr {
+            SRepr::Os(code) => SErrorKind::from_errorkind(&Error::from_raw_os_error(*code).kind()),
+            SRepr::Simple(kind)
+            | SRepr::SimpleMessage { kind, .. }
+            | SRepr::Custom { kind, .. } => *kind,
+        }
+    }
+    /// See documentation of [`std::io::Error::get_ref`]
+    pub fn get_ref(&self) -> Option<&SDynError> {
+        match &self.repr {
+            SRepr::Custom { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+    /// See documentation of [`std::io::Error::get_mut`]
+    pub fn get_mut(&mut self) -> Option<&mut SDynError> {
+        match &mut self.repr {
+            SRepr::Custom { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+    /// See documentation of [`std::io::Error::into_inner`]
+    pub fn into_inner(self) -> Option<SDynError> {
+        match self.repr {
+            SRepr::Custom { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+    /// Returns an `errno` value describing this error, for C callers.
+    ///
+    /// On unix OS errors return their raw code, everything else is mapped
+    /// from the [`kind`](Self::kind), falling back to `EIO`.
+    #[cfg(feature = "errno")]
+    pub fn to_errno(&self) -> i32 {
+        #[cfg(unix)]
+        if let SRepr::Os(code) = self.repr {
+            return code;
+        }
+
+        match self.kind() {
+            SErrorKind::NotFound => libc::ENOENT,
+            SErrorKind::PermissionDenied => libc::EACCES,
+            SErrorKind::ConnectionRefused => libc::ECONNREFUSED,
+            SErrorKind::ConnectionReset => libc::ECONNRESET,
+            SErrorKind::ConnectionAborted => libc::ECONNABORTED,
+            SErrorKind::NotConnected => libc::ENOTCONN,
+            SErrorKind::AddrInUse => libc::EADDRINUSE,
+            SErrorKind::AddrNotAvailable => libc::EADDRNOTAVAIL,
+            SErrorKind::BrokenPipe => libc::EPIPE,
+            SErrorKind::AlreadyExists => libc::EEXIST,
+            SErrorKind::WouldBlock => libc::EAGAIN,
+            SErrorKind::InvalidInput | SErrorKind::InvalidData => libc::EINVAL,
+            SErrorKind::TimedOut => libc::ETIMEDOUT,
+            SErrorKind::Interrupted => libc::EINTR,
+            SErrorKind::Unsupported => libc::ENOSYS,
+            SErrorKind::OutOfMemory => libc::ENOMEM,
+            SErrorKind::HostUnreachable => libc::EHOSTUNREACH,
+            SErrorKind::NetworkUnreachable => libc::ENETUNREACH,
+            SErrorKind::NetworkDown => libc::ENETDOWN,
+            SErrorKind::NotADirectory => libc::ENOTDIR,
+            SErrorKind::IsADirectory => libc::EISDIR,
+            SErrorKind::DirectoryNotEmpty => libc::ENOTEMPTY,
+            SErrorKind::ReadOnlyFilesystem => libc::EROFS,
+            #[cfg(unix)]
+            SErrorKind::StaleNetworkFileHandle => libc::ESTALE,
+            SErrorKind::StorageFull => libc::ENOSPC,
+            SErrorKind::NotSeekable => libc::ESPIPE,
+            #[cfg(unix)]
+            SErrorKind::QuotaExceeded => libc::EDQUOT,
+            SErrorKind::FileTooLarge => libc::EFBIG,
+            SErrorKind::ResourceBusy => libc::EBUSY,
+            SErrorKind::ExecutableFileBusy => libc::ETXTBSY,
+            SErrorKind::Deadlock => libc::EDEADLK,
+            SErrorKind::CrossesDevices => libc::EXDEV,
+            SErrorKind::TooManyLinks => libc::EMLINK,
+            SErrorKind::InvalidFilename => libc::ENAMETOOLONG,
+            SErrorKind::ArgumentListTooLong => libc::E2BIG,
+            _ => libc::EIO,
+        }
+    }
+}
+
+impl From<SErrorKind> for SError {
+    fn from(kind: SErrorKind) -> Self {
+        Self {
+            repr: SRepr::Simple(kind),
+        }
+    }
+}
+
+impl PartialEq<SErrorKind> for SError {
+    fn eq(&self, other: &SErrorKind) -> bool {
+        self.kind() == *other
+    }
+}
+
+impl PartialEq<ErrorKind> for SError {
+    fn eq(&self, other: &ErrorKind) -> bool {
+        self.kind() == SErrorKind::from_errorkind(other)
+    }
 }
 
 impl From<Error> for SError {
@@ -64,38 +226,53 @@ impl From<SError> for Error {
     }
 }
 
-impl std::error::Error for SError {}
+impl std::error::Error for SError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.get_ref().and_then(|e| e.source())
+    }
+}
 
+/// Formats exactly like `std::io::Error`
 impl Display for SError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.repr {
-            SRepr::Os(code) => {
-                write!(f, "os error: {}", code)
-            }
-            SRepr::Custom { kind, error } => {
-                if let &Some(ref error) = &*error.as_option() {
-                    write!(f, "{:?}: {}", kind.as_errorkind(), error)
-                } else {
-                    write!(f, "{:?}", kind.as_errorkind())
-                }
-            }
+            SRepr::Os(code) => Display::fmt(&Error::from_raw_os_error(*code), f),
+            SRepr::Simple(kind) => Display::fmt(&Error::from(kind.as_errorkind()), f),
+            SRepr::SimpleMessage { message, .. } => Display::fmt(message, f),
+            SRepr::Custom { error, .. } => Display::fmt(error, f),
         }
     }
 }
 
+/// Formats exactly like `std::io::Error`
 impl Debug for SError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.repr {
-            SRepr::Os(code) => {
-                write!(f, "os error: {}", code)
-            }
-            SRepr::Custom { kind, error } => {
-                if let &Some(ref error) = &*error.as_option() {
-                    write!(f, "{:?}: {:?}", kind.as_errorkind(), error)
-                } else {
-                    write!(f, "{:?}", kind.as_errorkind())
-                }
-            }
+            SRepr::Os(code) => Debug::fmt(&Error::from_raw_os_error(*code), f),
+            SRepr::Simple(kind) => f.debug_tuple("Kind").field(&kind.as_errorkind()).finish(),
+            SRepr::SimpleMessage { kind, message } => f
+                .debug_struct("Error")
+                .field("kind", &kind.as_errorkind())
+                .field("message", message)
+                .finish(),
+            SRepr::Custom { kind, error } => f
+                .debug_struct("Custom")
+                .field("kind", &kind.as_errorkind())
+                .field("error", error)
+                .finish(),
         }
     }
 }
+
+impl IntoSafe for Error {
+    type Safe = SError;
+
+    fn into_safe(self) -> Self::Safe {
+        SError::from_error(self)
+    }
+}
+impl FromSafe for Error {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_error()
+    }
+}
@@ -1,82 +1,110 @@
-use std::io::ErrorKind;
+use crate::{FromSafe, IntoSafe};
+use std::{fmt::Debug, io::ErrorKind};
 
-/// A list specifying general categories of I/O error.
-///
-/// See documentation of [`std::io::ErrorKind`]
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, PartialOrd, Ord)]
-#[repr(C)]
-pub enum SErrorKind {
-    NotFound,
-    PermissionDenied,
-    ConnectionRefused,
-    ConnectionReset,
-    ConnectionAborted,
-    NotConnected,
-    AddrInUse,
-    AddrNotAvailable,
-    BrokenPipe,
-    AlreadyExists,
-    WouldBlock,
-    InvalidInput,
-    InvalidData,
-    TimedOut,
-    WriteZero,
-    Interrupted,
-    Unsupported,
-    UnexpectedEof,
-    OutOfMemory,
-    Other,
-}
+macro_rules! error_kinds {
+    ($($kind:ident = $code:literal,)*) => {
+        /// A list specifying general categories of I/O error.
+        ///
+        /// See documentation of [`std::io::ErrorKind`]
+        ///
+        /// This is a stable code rather than an enum, so that any code is valid in every
+        /// binary. Codes must never be changed or reused, new kinds only get new codes.
+        /// A kind that this version of the crate doesn't know about (for example, one
+        /// created by a plugin compiled against a newer version) keeps its code, so it
+        /// survives being passed around.
+        ///
+        /// The known kinds are associated constants, so they can be matched on like
+        /// enum variants.
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(transparent)]
+        pub struct SErrorKind(u32);
+
+        #[allow(non_upper_case_globals)]
+        impl SErrorKind {
+            $(
+                #[doc = concat!("See documentation of [`std::io::ErrorKind::", stringify!($kind), "`]")]
+                pub const $kind: Self = Self($code);
+            )*
 
-impl SErrorKind {
-    pub fn from_errorkind(e: &ErrorKind) -> Self {
-        match e {
-            ErrorKind::NotFound => Self::NotFound,
-            ErrorKind::PermissionDenied => Self::PermissionDenied,
-            ErrorKind::ConnectionRefused => Self::ConnectionRefused,
-            ErrorKind::ConnectionReset => Self::ConnectionReset,
-            ErrorKind::ConnectionAborted => Self::ConnectionAborted,
-            ErrorKind::NotConnected => Self::NotConnected,
-            ErrorKind::AddrInUse => Self::AddrInUse,
-            ErrorKind::AddrNotAvailable => Self::AddrNotAvailable,
-            ErrorKind::BrokenPipe => Self::BrokenPipe,
-            ErrorKind::AlreadyExists => Self::AlreadyExists,
-            ErrorKind::WouldBlock => Self::WouldBlock,
-            ErrorKind::InvalidInput => Self::InvalidInput,
-            ErrorKind::InvalidData => Self::InvalidData,
-            ErrorKind::TimedOut => Self::TimedOut,
-            ErrorKind::WriteZero => Self::WriteZero,
-            ErrorKind::Interrupted => Self::Interrupted,
-            ErrorKind::Unsupported => Self::Unsupported,
-            ErrorKind::UnexpectedEof => Self::UnexpectedEof,
-            ErrorKind::OutOfMemory => Self::OutOfMemory,
-            _ => Self::Other,
+            /// Kinds that don't exist here map to [`SErrorKind::Other`]
+            pub fn from_errorkind(e: &ErrorKind) -> Self {
+                match e {
+                    $(ErrorKind::$kind => Self::$kind,)*
+                    _ => Self::Other,
+                }
+            }
+            /// Unknown kinds map to [`ErrorKind::Other`]
+            pub fn as_errorkind(&self) -> ErrorKind {
+                match *self {
+                    $(Self::$kind => ErrorKind::$kind,)*
+                    _ => ErrorKind::Other,
+                }
+            }
+            /// Returns the stable code of this kind
+            pub fn as_code(&self) -> u32 {
+                self.0
+            }
+            /// Inverse of [`SErrorKind::as_code`], unknown codes are kept as they are
+            pub fn from_code(code: u32) -> Self {
+                Self(code)
+            }
+            /// Returns `true` if this version of the crate knows the kind
+            pub fn is_known(&self) -> bool {
+                matches!(*self, $(Self::$kind)|*)
+            }
         }
-    }
-    pub fn as_errorkind(&self) -> ErrorKind {
-        match self {
-            Self::NotFound => ErrorKind::NotFound,
-            Self::PermissionDenied => ErrorKind::PermissionDenied,
-            Self::ConnectionRefused => ErrorKind::ConnectionRefused,
-            Self::ConnectionReset => ErrorKind::ConnectionReset,
-            Self::ConnectionAborted => ErrorKind::ConnectionAborted,
-            Self::NotConnected => ErrorKind::NotConnected,
-            Self::AddrInUse => ErrorKind::AddrInUse,
-            Self::AddrNotAvailable => ErrorKind::AddrNotAvailable,
-            Self::BrokenPipe => ErrorKind::BrokenPipe,
-            Self::AlreadyExists => ErrorKind::AlreadyExists,
-            Self::WouldBlock => ErrorKind::WouldBlock,
-            Self::InvalidInput => ErrorKind::InvalidInput,
-            Self::InvalidData => ErrorKind::InvalidData,
-            Self::TimedOut => ErrorKind::TimedOut,
-            Self::WriteZero => ErrorKind::WriteZero,
-            Self::Interrupted => ErrorKind::Interrupted,
-            Self::Unsupported => ErrorKind::Unsupported,
-            Self::UnexpectedEof => ErrorKind::UnexpectedEof,
-            Self::OutOfMemory => ErrorKind::OutOfMemory,
-            _ => ErrorKind::Other,
+
+        impl Debug for SErrorKind {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match *self {
+                    $(Self::$kind => f.write_str(stringify!($kind)),)*
+                    Self(code) => f.debug_tuple("Unknown").field(&code).finish(),
+                }
+            }
         }
-    }
+    };
+}
+
+error_kinds! {
+    NotFound = 0,
+    PermissionDenied = 1,
+    ConnectionRefused = 2,
+    ConnectionReset = 3,
+    ConnectionAborted = 4,
+    NotConnected = 5,
+    AddrInUse = 6,
+    AddrNotAvailable = 7,
+    BrokenPipe = 8,
+    AlreadyExists = 9,
+    WouldBlock = 10,
+    InvalidInput = 11,
+    InvalidData = 12,
+    TimedOut = 13,
+    WriteZero = 14,
+    Interrupted = 15,
+    Unsupported = 16,
+    UnexpectedEof = 17,
+    OutOfMemory = 18,
+    Other = 19,
+    HostUnreachable = 20,
+    NetworkUnreachable = 21,
+    NetworkDown = 22,
+    NotADirectory = 23,
+    IsADirectory = 24,
+    DirectoryNotEmpty = 25,
+    ReadOnlyFilesystem = 26,
+    StaleNetworkFileHandle = 27,
+    StorageFull = 28,
+    NotSeekable = 29,
+    QuotaExceeded = 30,
+    FileTooLarge = 31,
+    ResourceBusy = 32,
+    ExecutableFileBusy = 33,
+    Deadlock = 34,
+    CrossesDevices = 35,
+    TooManyLinks = 36,
+    InvalidFilename = 37,
+    ArgumentListTooLong = 38,
 }
 
 impl From<ErrorKind> for SErrorKind {
@@ -90,3 +118,16 @@ impl From<SErrorKind> for ErrorKind {
         e.as_errorkind()
     }
 }
+
+impl IntoSafe for ErrorKind {
+    type Safe = SErrorKind;
+
+    fn into_safe(self) -> Self::Safe {
+        SErrorKind::from_errorkind(&self)
+    }
+}
+impl FromSafe for ErrorKind {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_errorkind()
+    }
+}
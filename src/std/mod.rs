@@ -1,19 +1,27 @@
 pub mod borrow;
 pub mod boxed;
+pub mod cell;
 pub mod error;
+pub mod future;
 pub mod io;
 pub mod net;
 pub mod option;
+pub mod panic;
+pub mod rc;
 pub mod result;
 pub mod string;
 pub mod sync;
 pub mod task;
+pub mod thread;
 pub mod time;
 pub mod vec;
 
+mod refcount;
+
 pub mod prelude {
     pub use crate::std::option::SOption::{self, None as SNone, Some as SSome};
     pub use crate::std::result::SResult::{self, Err as SErr, Ok as SOk};
     pub use crate::std::string::SString;
     pub use crate::std::vec::SVec;
+    pub use crate::{FromSafe, IntoSafe};
 }
@@ -1,4 +1,5 @@
 use super::{SIpv4Addr, SIpv6Addr};
+use crate::{FromSafe, IntoSafe};
 use std::{
     fmt::Display,
     net::{IpAddr, Ipv4Addr, Ipv6Addr},
@@ -161,3 +162,16 @@ impl PartialOrd<SIpv6Addr> for SIpAddr {
         PartialOrd::partial_cmp(&self.as_ipaddr(), &other.as_ipv6addr())
     }
 }
+
+impl IntoSafe for IpAddr {
+    type Safe = SIpAddr;
+
+    fn into_safe(self) -> Self::Safe {
+        SIpAddr::from_ipaddr(&self)
+    }
+}
+impl FromSafe for IpAddr {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_ipaddr()
+    }
+}
@@ -1,12 +1,15 @@
 use super::{SIpAddr};
+use crate::{FromSafe, IntoSafe};
 use std::{
     fmt::Display,
-    net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr},
+    net::{AddrParseError, IpAddr, Ipv4Addr},
     str::FromStr,
 };
 
 #[cfg(feature = "convenient_methods")]
 use safe_types_derive::impl_methods;
+#[cfg(feature = "convenient_methods")]
+use std::net::Ipv6Addr;
 
 /// An IPv4 address.
 ///
@@ -138,3 +141,16 @@ impl PartialOrd<SIpAddr> for SIpv4Addr {
         PartialOrd::partial_cmp(&self.as_ipv4addr(), &other.as_ipaddr())
     }
 }
+
+impl IntoSafe for Ipv4Addr {
+    type Safe = SIpv4Addr;
+
+    fn into_safe(self) -> Self::Safe {
+        SIpv4Addr::from_ipv4addr(&self)
+    }
+}
+impl FromSafe for Ipv4Addr {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_ipv4addr()
+    }
+}
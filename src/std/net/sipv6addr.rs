@@ -1,12 +1,15 @@
 use super::SIpAddr;
+use crate::{FromSafe, IntoSafe};
 use std::{
     fmt::Display,
-    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    net::{IpAddr, Ipv6Addr},
     str::FromStr,
 };
 
 #[cfg(feature = "convenient_methods")]
 use safe_types_derive::impl_methods;
+#[cfg(feature = "convenient_methods")]
+use std::net::Ipv4Addr;
 
 /// An IPv6 address.
 ///
@@ -28,6 +31,7 @@ impl SIpv6Addr {
     pub const LOCALHOST: Self = Self::new(0, 0, 0, 0, 0, 0, 0, 1);
     pub const UNSPECIFIED: Self = Self::new(0, 0, 0, 0, 0, 0, 0, 0);
 
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(a: u16, b: u16, c: u16, d: u16, e: u16, f: u16, g: u16, h: u16) -> Self {
         Self {
             a,
@@ -151,3 +155,16 @@ impl PartialOrd<SIpAddr> for SIpv6Addr {
         PartialOrd::partial_cmp(&self.as_ipv6addr(), &other.as_ipaddr())
     }
 }
+
+impl IntoSafe for Ipv6Addr {
+    type Safe = SIpv6Addr;
+
+    fn into_safe(self) -> Self::Safe {
+        SIpv6Addr::from_ipv6addr(&self)
+    }
+}
+impl FromSafe for Ipv6Addr {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_ipv6addr()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{FromSafe, IntoSafe};
 use std::net::Shutdown;
 
 /// Possible values which can be passed to the `TcpStream::shutdown` method.
@@ -38,3 +39,16 @@ impl From<SShutdown> for Shutdown {
         s.as_shutdown()
     }
 }
+
+impl IntoSafe for Shutdown {
+    type Safe = SShutdown;
+
+    fn into_safe(self) -> Self::Safe {
+        SShutdown::from_shutdown(self)
+    }
+}
+impl FromSafe for Shutdown {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_shutdown()
+    }
+}
@@ -1,5 +1,5 @@
 use super::{SIpAddr, SSocketAddrV4, SSocketAddrV6};
-use crate::Mutable;
+use crate::{FromSafe, IntoSafe, Mutable};
 use std::{
     net::{IpAddr, SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs},
     str::FromStr,
@@ -102,3 +102,16 @@ impl ToSocketAddrs for SSocketAddr {
         ToSocketAddrs::to_socket_addrs(&self.as_socketaddr())
     }
 }
+
+impl IntoSafe for SocketAddr {
+    type Safe = SSocketAddr;
+
+    fn into_safe(self) -> Self::Safe {
+        SSocketAddr::from_socketaddr(&self)
+    }
+}
+impl FromSafe for SocketAddr {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_socketaddr()
+    }
+}
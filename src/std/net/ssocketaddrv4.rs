@@ -1,5 +1,5 @@
 use super::SIpv4Addr;
-use crate::{Mutable};
+use crate::{FromSafe, IntoSafe, Mutable};
 use std::{
     fmt::Display,
     net::{Ipv4Addr, SocketAddrV4, ToSocketAddrs},
@@ -78,3 +78,16 @@ impl ToSocketAddrs for SSocketAddrV4 {
         ToSocketAddrs::to_socket_addrs(&self.as_socketaddrv4())
     }
 }
+
+impl IntoSafe for SocketAddrV4 {
+    type Safe = SSocketAddrV4;
+
+    fn into_safe(self) -> Self::Safe {
+        SSocketAddrV4::from_socketaddrv4(&self)
+    }
+}
+impl FromSafe for SocketAddrV4 {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_socketaddrv4()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Mutable};
+use crate::{FromSafe, IntoSafe, Mutable};
 
 use super::SIpv6Addr;
 use std::{
@@ -101,3 +101,16 @@ impl ToSocketAddrs for SSocketAddrV6 {
         ToSocketAddrs::to_socket_addrs(&self.as_socketaddrv6())
     }
 }
+
+impl IntoSafe for SocketAddrV6 {
+    type Safe = SSocketAddrV6;
+
+    fn into_safe(self) -> Self::Safe {
+        SSocketAddrV6::from_socketaddrv6(&self)
+    }
+}
+impl FromSafe for SocketAddrV6 {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_socketaddrv6()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Immutable, Mutable};
+use crate::{FromSafe, Immutable, IntoSafe, Mutable};
 use std::fmt::Debug;
 use std::io::{Read, Write};
 use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
@@ -113,3 +113,16 @@ impl From<STcpStream> for TcpStream {
         s.into_tcpstream()
     }
 }
+
+impl IntoSafe for TcpStream {
+    type Safe = STcpStream;
+
+    fn into_safe(self) -> Self::Safe {
+        STcpStream::from_tcpstream(self)
+    }
+}
+impl FromSafe for TcpStream {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_tcpstream()
+    }
+}
@@ -1,5 +1,5 @@
 use super::result::SResult;
-use crate::{Immutable, Mutable};
+use crate::{FromSafe, Immutable, IntoSafe, Mutable};
 use std::ops::{Deref, DerefMut};
 
 #[cfg(feature = "convenient_methods")]
@@ -8,10 +8,11 @@ use safe_types_derive::impl_methods;
 /// The `Option` type.
 ///
 /// See documentation of [`std::option::Option`]
-#[derive(Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[derive(Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Default)]
 #[repr(C)]
 pub enum SOption<T> {
     Some(T),
+    #[default]
     None,
 }
 
@@ -126,18 +127,18 @@ impl<T> SOption<SOption<T>> {
     }
 }
 
-impl<'a, T: Clone> SOption<&'a T> {
+impl<T: Clone> SOption<&T> {
     pub fn cloned(self) -> SOption<T> {
-        SOption::from_option(self.into_option().map(|t| t.clone()))
+        SOption::from_option(self.into_option().cloned())
     }
 }
-impl<'a, T: Clone> SOption<&'a mut T> {
+impl<T: Clone> SOption<&mut T> {
     pub fn cloned(self) -> SOption<T> {
-        SOption::from_option(self.into_option().map(|t| t.clone()))
+        SOption::from_option(self.into_option().cloned())
     }
 }
 
-impl<'a, T: Copy> SOption<&'a T> {
+impl<T: Copy> SOption<&T> {
     pub fn copied(self) -> SOption<T> {
         match self {
             Self::Some(&v) => SOption::Some(v),
@@ -145,7 +146,7 @@ impl<'a, T: Copy> SOption<&'a T> {
         }
     }
 }
-impl<'a, T: Copy> SOption<&'a mut T> {
+impl<T: Copy> SOption<&mut T> {
     pub fn copied(self) -> SOption<T> {
         match self {
             Self::Some(&mut v) => SOption::Some(v),
@@ -181,12 +182,6 @@ impl<T: Clone> Clone for SOption<T> {
     }
 }
 
-impl<T> Default for SOption<T> {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl<T> From<Option<T>> for SOption<T> {
     fn from(r: Option<T>) -> Self {
         Self::from_option(r)
@@ -222,3 +217,16 @@ impl<T> From<T> for SOption<T> {
         Self::Some(r)
     }
 }
+
+impl<T: IntoSafe> IntoSafe for Option<T> {
+    type Safe = SOption<T::Safe>;
+
+    fn into_safe(self) -> Self::Safe {
+        SOption::from_option(self.map(IntoSafe::into_safe))
+    }
+}
+impl<T: FromSafe> FromSafe for Option<T> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_option().map(T::from_safe)
+    }
+}
@@ -0,0 +1,62 @@
+mod slocation;
+mod spanicinfo;
+
+pub use slocation::SLocation;
+pub use spanicinfo::SPanicInfo;
+
+use crate::std::prelude::SResult;
+use std::{
+    cell::RefCell,
+    panic::{catch_unwind, UnwindSafe},
+    sync::Once,
+};
+
+thread_local! {
+    static LOCATION: RefCell<Option<SLocation>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Panic payloads don't carry the location, so a hook records it. Any previously
+/// set hook is still called.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let location = info.location().map(SLocation::from_location);
+            let _ = LOCATION.try_with(|l| {
+                if let Ok(mut l) = l.try_borrow_mut() {
+                    *l = location;
+                }
+            });
+
+            previous(info);
+        }));
+    });
+}
+
+/// Invokes a closure, capturing the cause of an unwinding panic if one occurs.
+///
+/// See documentation of [`std::panic::catch_unwind`]
+///
+/// Unwinding out of an `extern "C"` function aborts, so this should wrap the body of
+/// every function that is called across the FFI boundary. The panic can be continued
+/// on the other side with [`SPanicInfo::resume_unwind`].
+///
+/// The first call installs a panic hook that records panic locations, calling
+/// whatever hook was set before. If the hook is replaced later, locations won't be available.
+pub fn ffi_catch_unwind<F: FnOnce() -> T + UnwindSafe, T>(f: F) -> SResult<T, SPanicInfo> {
+    install_hook();
+    // `resume_unwind` doesn't call the hook, so a location left over
+    // from an earlier panic must not be picked up
+    LOCATION.with(|l| *l.borrow_mut() = None);
+
+    match catch_unwind(f) {
+        Ok(v) => SResult::Ok(v),
+        Err(payload) => {
+            let location = LOCATION.with(|l| l.borrow_mut().take());
+
+            SResult::Err(SPanicInfo::from_payload(&*payload, location))
+        }
+    }
+}
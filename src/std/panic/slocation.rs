@@ -0,0 +1,46 @@
+use crate::std::string::SString;
+use std::{fmt::Display, panic::Location};
+
+/// A struct containing information about the location of a panic.
+///
+/// See documentation of [`std::panic::Location`]
+///
+/// Unlike the std type, this owns the file name.
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct SLocation {
+    file: SString,
+    line: u32,
+    column: u32,
+}
+
+impl SLocation {
+    pub fn from_location(l: &Location) -> Self {
+        Self {
+            file: l.file().to_owned().into(),
+            line: l.line(),
+            column: l.column(),
+        }
+    }
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl<'a> From<&Location<'a>> for SLocation {
+    fn from(l: &Location<'a>) -> Self {
+        Self::from_location(l)
+    }
+}
+
+impl Display for SLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file(), self.line, self.column)
+    }
+}
@@ -0,0 +1,65 @@
+use super::SLocation;
+use crate::std::{option::SOption, string::SString};
+use std::{
+    any::Any,
+    error::Error,
+    fmt::{Debug, Display},
+};
+
+/// Information about a panic caught by [`ffi_catch_unwind`](super::ffi_catch_unwind).
+///
+/// See documentation of [`std::panic::PanicHookInfo`]
+///
+/// Only the message of the payload is kept, since the payload itself is
+/// an arbitrary `Box<dyn Any>`.
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct SPanicInfo {
+    message: SString,
+    location: SOption<SLocation>,
+}
+
+impl SPanicInfo {
+    pub fn new(message: String, location: Option<SLocation>) -> Self {
+        Self {
+            message: message.into(),
+            location: SOption::from_option(location),
+        }
+    }
+    pub(crate) fn from_payload(payload: &(dyn Any + Send), location: Option<SLocation>) -> Self {
+        let message = if let Some(s) = payload.downcast_ref::<&str>() {
+            (*s).to_owned()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "Box<dyn Any>".to_owned()
+        };
+
+        Self::new(message, location)
+    }
+    /// The panic message, or `Box<dyn Any>` if the payload wasn't a string
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    pub fn location(&self) -> Option<&SLocation> {
+        self.location.as_ref()
+    }
+    /// Continues the panic, with the message as a `String` payload.
+    ///
+    /// See documentation of [`std::panic::resume_unwind`], like it, this doesn't
+    /// invoke the panic hook.
+    pub fn resume_unwind(self) -> ! {
+        std::panic::resume_unwind(Box::new(self.message.into_string()))
+    }
+}
+
+impl Display for SPanicInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self.location.as_option() {
+            Some(location) => write!(f, "panicked at {}:\n{}", location, self.message()),
+            None => write!(f, "panicked:\n{}", self.message()),
+        }
+    }
+}
+
+impl Error for SPanicInfo {}
@@ -0,0 +1,7 @@
+mod src;
+mod srcopaque;
+mod sweakrc;
+
+pub use src::SRc;
+pub use srcopaque::{SRcOpaque, SWeakRcOpaque};
+pub use sweakrc::SWeakRc;
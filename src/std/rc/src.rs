@@ -0,0 +1,16 @@
+use super::SWeakRc;
+use crate::std::refcount::strong_ptr;
+use std::rc::{Rc, Weak};
+
+strong_ptr! {
+    /// A single-threaded reference-counting pointer.
+    ///
+    /// See documentation of [`std::rc::Rc`]
+    ///
+    /// Like [`SArc`](crate::std::sync::SArc), the reference counts are managed by
+    /// function pointers of the binary that created the `Rc`. It can only be converted back
+    /// to an `Rc` in the binary that created it. Like `Rc`, it is neither `Send` nor `Sync`.
+    SRc(Rc, SRcVTable),
+    weak: SWeakRc(Weak),
+    from_rc, try_into_rc, into_rc,
+}
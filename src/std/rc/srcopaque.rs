@@ -0,0 +1,25 @@
+use super::{
+    src::{SRcVTable, VTableOf},
+    SRc,
+};
+use crate::std::refcount::opaque_ptrs;
+use std::rc::Rc;
+
+opaque_ptrs! {
+    /// Opaque SRc for keeping the reference count
+    ///
+    /// The single-threaded version of [`SArcOpaque`](crate::std::sync::SArcOpaque).
+    /// Like `Rc`, it is neither `Send` nor `Sync`.
+    ///
+    /// The original `Rc` can be recovered with [`SRcOpaque::try_into_rc`],
+    /// but only in the binary that created it. An `SRc` created in another binary
+    /// keeps working, but can't be recovered anywhere.
+    SRcOpaque(Rc, SRcVTable),
+    /// A version of [`SRcOpaque`] that holds a non-owning reference to the managed allocation.
+    ///
+    /// See documentation of [`std::rc::Weak`]
+    weak: SWeakRcOpaque,
+    strong: SRc,
+    try_into_rc,
+    bounds: [],
+}
@@ -0,0 +1,11 @@
+use super::src::{SRc, SRcVTable, VTableOf};
+use crate::std::refcount::weak_ptr;
+use std::rc::Weak;
+
+weak_ptr! {
+    /// A version of [`SRc`] that holds a non-owning reference to the managed allocation.
+    ///
+    /// See documentation of [`std::rc::Weak`]
+    SWeakRc(Weak, SRcVTable),
+    strong: SRc,
+}
@@ -0,0 +1,585 @@
+//! `Arc` and `Rc` only differ in thread safety, so the FFI-safe versions of both
+//! (and of their weak and opaque counterparts) are generated by the macros here.
+//! `Send` and `Sync` are left to the callers, and the weak and opaque macros expect
+//! the `VTableOf` generated next to the strong pointer to be in scope.
+
+/// Generates a reference-counted pointer managed through a vtable of the binary that created it
+macro_rules! strong_ptr {
+    (
+        $(#[$attr:meta])*
+        $name:ident($std:ident, $vtable:ident),
+        weak: $weak:ident($std_weak:ident),
+        $from:ident, $try_into:ident, $into:ident $(,)?
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        pub struct $name<T> {
+            pub(super) ptr: *const T,
+            pub(super) vtable: &'static $vtable,
+        }
+
+        #[repr(C)]
+        pub(super) struct $vtable {
+            pub(super) clone: unsafe extern "C" fn(*const ()),
+            pub(super) drop: unsafe extern "C" fn(*const ()),
+            pub(super) strong_count: unsafe extern "C" fn(*const ()) -> usize,
+            pub(super) weak_count: unsafe extern "C" fn(*const ()) -> usize,
+            /// Returns null if the pointer is not unique
+            get_mut: unsafe extern "C" fn(*const ()) -> *mut (),
+            /// Writes the value to the second argument and returns `true` if the pointer was unique
+            try_unwrap: unsafe extern "C" fn(*const (), *mut ()) -> bool,
+            pub(super) downgrade: unsafe extern "C" fn(*const ()) -> *const (),
+            pub(super) weak_clone: unsafe extern "C" fn(*const ()),
+            pub(super) weak_drop: unsafe extern "C" fn(*const ()),
+            pub(super) weak_strong_count: unsafe extern "C" fn(*const ()) -> usize,
+            pub(super) weak_weak_count: unsafe extern "C" fn(*const ()) -> usize,
+            /// Returns null if there are no strong references left
+            pub(super) upgrade: unsafe extern "C" fn(*const ()) -> *const (),
+            pub(super) binary: extern "C" fn() -> *const u8,
+        }
+
+        pub(super) struct VTableOf<T>(std::marker::PhantomData<T>);
+
+        impl<T> VTableOf<T> {
+            pub(super) const VTABLE: $vtable = $vtable {
+                clone: Self::clone,
+                drop: Self::drop,
+                strong_count: Self::strong_count,
+                weak_count: Self::weak_count,
+                get_mut: Self::get_mut,
+                try_unwrap: Self::try_unwrap,
+                downgrade: Self::downgrade,
+                weak_clone: Self::weak_clone,
+                weak_drop: Self::weak_drop,
+                weak_strong_count: Self::weak_strong_count,
+                weak_weak_count: Self::weak_weak_count,
+                upgrade: Self::upgrade,
+                binary: $crate::type_tag::binary_id,
+            };
+            /// Behaves like `VTABLE`, but claims to belong to another binary
+            const FOREIGN_VTABLE: $vtable = $vtable {
+                binary: $crate::type_tag::foreign_binary_id,
+                ..Self::VTABLE
+            };
+
+            unsafe fn strong(ptr: *const ()) -> std::mem::ManuallyDrop<$std<T>> {
+                std::mem::ManuallyDrop::new(unsafe { $std::from_raw(ptr as *const T) })
+            }
+            unsafe fn weak(ptr: *const ()) -> std::mem::ManuallyDrop<$std_weak<T>> {
+                std::mem::ManuallyDrop::new(unsafe { $std_weak::from_raw(ptr as *const T) })
+            }
+
+            unsafe extern "C" fn clone(ptr: *const ()) {
+                unsafe { $std::increment_strong_count(ptr as *const T) }
+            }
+            unsafe extern "C" fn drop(ptr: *const ()) {
+                unsafe { $std::decrement_strong_count(ptr as *const T) }
+            }
+            unsafe extern "C" fn strong_count(ptr: *const ()) -> usize {
+                $std::strong_count(unsafe { &Self::strong(ptr) })
+            }
+            unsafe extern "C" fn weak_count(ptr: *const ()) -> usize {
+                $std::weak_count(unsafe { &Self::strong(ptr) })
+            }
+            unsafe extern "C" fn get_mut(ptr: *const ()) -> *mut () {
+                match $std::get_mut(unsafe { &mut Self::strong(ptr) }) {
+                    Some(r) => r as *mut T as *mut (),
+                    None => std::ptr::null_mut(),
+                }
+            }
+            unsafe extern "C" fn try_unwrap(ptr: *const (), out: *mut ()) -> bool {
+                match $std::try_unwrap(unsafe { $std::from_raw(ptr as *const T) }) {
+                    Ok(v) => {
+                        unsafe { (out as *mut T).write(v) };
+                        true
+                    }
+                    Err(strong) => {
+                        // the pointer stays the same
+                        let _ = $std::into_raw(strong);
+                        false
+                    }
+                }
+            }
+            unsafe extern "C" fn downgrade(ptr: *const ()) -> *const () {
+                $std_weak::into_raw($std::downgrade(unsafe { &Self::strong(ptr) })) as *const ()
+            }
+            unsafe extern "C" fn weak_clone(ptr: *const ()) {
+                let _ = $std_weak::into_raw($std_weak::clone(unsafe { &Self::weak(ptr) }));
+            }
+            unsafe extern "C" fn weak_drop(ptr: *const ()) {
+                drop(unsafe { $std_weak::from_raw(ptr as *const T) })
+            }
+            unsafe extern "C" fn weak_strong_count(ptr: *const ()) -> usize {
+                unsafe { Self::weak(ptr) }.strong_count()
+            }
+            unsafe extern "C" fn weak_weak_count(ptr: *const ()) -> usize {
+                unsafe { Self::weak(ptr) }.weak_count()
+            }
+            unsafe extern "C" fn upgrade(ptr: *const ()) -> *const () {
+                match unsafe { Self::weak(ptr) }.upgrade() {
+                    Some(strong) => $std::into_raw(strong) as *const (),
+                    None => std::ptr::null(),
+                }
+            }
+        }
+
+        impl<T> $name<T> {
+            pub fn new(value: T) -> Self {
+                Self::$from($std::new(value))
+            }
+            pub fn $from(ptr: $std<T>) -> Self {
+                Self {
+                    ptr: $std::into_raw(ptr),
+                    vtable: &VTableOf::<T>::VTABLE,
+                }
+            }
+            /// Creates a pointer that behaves as if another binary had created it, for tests
+            #[doc(hidden)]
+            pub fn __new_foreign(value: T) -> Self {
+                Self {
+                    ptr: $std::into_raw($std::new(value)),
+                    vtable: &VTableOf::<T>::FOREIGN_VTABLE,
+                }
+            }
+            #[doc = concat!("Returns the original `", stringify!($std), "` if it was created in this binary")]
+            pub fn $try_into(self) -> Result<$std<T>, Self> {
+                if (self.vtable.binary)() != $crate::type_tag::binary_id() {
+                    return Err(self);
+                }
+
+                let this = std::mem::ManuallyDrop::new(self);
+                Ok(unsafe { $std::from_raw(this.ptr) })
+            }
+            #[doc = concat!("Returns the original `", stringify!($std), "` if it was created in this binary, otherwise")]
+            /// clones the value into a new one.
+            pub fn $into(self) -> $std<T>
+            where
+                T: Clone,
+            {
+                match self.$try_into() {
+                    Ok(ptr) => ptr,
+                    Err(this) => $std::new((*this).clone()),
+                }
+            }
+            pub fn as_ptr(this: &Self) -> *const T {
+                this.ptr
+            }
+            pub fn strong_count(this: &Self) -> usize {
+                unsafe { (this.vtable.strong_count)(this.ptr as *const ()) }
+            }
+            pub fn weak_count(this: &Self) -> usize {
+                unsafe { (this.vtable.weak_count)(this.ptr as *const ()) }
+            }
+            pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+                this.ptr == other.ptr
+            }
+            pub fn get_mut(this: &mut Self) -> Option<&mut T> {
+                let ptr = unsafe { (this.vtable.get_mut)(this.ptr as *const ()) };
+
+                unsafe { (ptr as *mut T).as_mut() }
+            }
+            #[doc = concat!("See documentation of [`", stringify!($std), "::make_mut`].")]
+            ///
+            /// If the value has to be cloned, the new one is allocated by this binary.
+            pub fn make_mut(this: &mut Self) -> &mut T
+            where
+                T: Clone,
+            {
+                if Self::get_mut(this).is_none() {
+                    *this = Self::new((**this).clone());
+                }
+
+                Self::get_mut(this).unwrap()
+            }
+            pub fn try_unwrap(this: Self) -> Result<T, Self> {
+                let mut value = std::mem::MaybeUninit::<T>::uninit();
+                let out = value.as_mut_ptr() as *mut ();
+
+                if unsafe { (this.vtable.try_unwrap)(this.ptr as *const (), out) } {
+                    std::mem::forget(this);
+                    Ok(unsafe { value.assume_init() })
+                } else {
+                    Err(this)
+                }
+            }
+            pub fn downgrade(this: &Self) -> $weak<T> {
+                $weak {
+                    ptr: unsafe { (this.vtable.downgrade)(this.ptr as *const ()) } as *const T,
+                    vtable: this.vtable,
+                }
+            }
+        }
+
+        impl<T> Clone for $name<T> {
+            fn clone(&self) -> Self {
+                unsafe { (self.vtable.clone)(self.ptr as *const ()) };
+
+                Self {
+                    ptr: self.ptr,
+                    vtable: self.vtable,
+                }
+            }
+        }
+
+        impl<T> Drop for $name<T> {
+            fn drop(&mut self) {
+                unsafe { (self.vtable.drop)(self.ptr as *const ()) }
+            }
+        }
+
+        impl<T> std::ops::Deref for $name<T> {
+            type Target = T;
+
+            fn deref(&self) -> &Self::Target {
+                unsafe { &*self.ptr }
+            }
+        }
+
+        impl<T> AsRef<T> for $name<T> {
+            fn as_ref(&self) -> &T {
+                self
+            }
+        }
+
+        impl<T> std::borrow::Borrow<T> for $name<T> {
+            fn borrow(&self) -> &T {
+                self
+            }
+        }
+
+        impl<T: std::fmt::Debug> std::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Debug::fmt(&**self, f)
+            }
+        }
+
+        impl<T: std::fmt::Display> std::fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&**self, f)
+            }
+        }
+
+        impl<T: Default> Default for $name<T> {
+            fn default() -> Self {
+                Self::new(T::default())
+            }
+        }
+
+        impl<T: PartialEq> PartialEq for $name<T> {
+            fn eq(&self, other: &Self) -> bool {
+                **self == **other
+            }
+        }
+
+        impl<T: Eq> Eq for $name<T> {}
+
+        impl<T: PartialOrd> PartialOrd for $name<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                (**self).partial_cmp(&**other)
+            }
+        }
+
+        impl<T: Ord> Ord for $name<T> {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                (**self).cmp(&**other)
+            }
+        }
+
+        impl<T: std::hash::Hash> std::hash::Hash for $name<T> {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                (**self).hash(state)
+            }
+        }
+
+        impl<T> From<T> for $name<T> {
+            fn from(value: T) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<T> From<$std<T>> for $name<T> {
+            fn from(ptr: $std<T>) -> Self {
+                Self::$from(ptr)
+            }
+        }
+
+        impl<T> $crate::IntoSafe for $std<T> {
+            type Safe = $name<T>;
+
+            fn into_safe(self) -> Self::Safe {
+                $name::$from(self)
+            }
+        }
+        impl<T: Clone> $crate::FromSafe for $std<T> {
+            fn from_safe(safe: Self::Safe) -> Self {
+                safe.$into()
+            }
+        }
+    };
+}
+
+/// Generates the weak counterpart of a [`strong_ptr`]
+macro_rules! weak_ptr {
+    (
+        $(#[$attr:meta])*
+        $name:ident($std_weak:ident, $vtable:ident),
+        strong: $strong:ident $(,)?
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        pub struct $name<T> {
+            pub(super) ptr: *const T,
+            pub(super) vtable: &'static $vtable,
+        }
+
+        impl<T> $name<T> {
+            #[doc = concat!("Constructs a new `", stringify!($name), "<T>`, without allocating any memory.")]
+            /// Calling `upgrade` on the return value always gives `None`.
+            pub fn new() -> Self {
+                Self {
+                    ptr: $std_weak::into_raw($std_weak::<T>::new()),
+                    vtable: &VTableOf::<T>::VTABLE,
+                }
+            }
+            pub fn upgrade(&self) -> Option<$strong<T>> {
+                let ptr = unsafe { (self.vtable.upgrade)(self.ptr as *const ()) };
+
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some($strong {
+                        ptr: ptr as *const T,
+                        vtable: self.vtable,
+                    })
+                }
+            }
+            pub fn strong_count(&self) -> usize {
+                unsafe { (self.vtable.weak_strong_count)(self.ptr as *const ()) }
+            }
+            pub fn weak_count(&self) -> usize {
+                unsafe { (self.vtable.weak_weak_count)(self.ptr as *const ()) }
+            }
+            pub fn ptr_eq(&self, other: &Self) -> bool {
+                self.ptr == other.ptr
+            }
+        }
+
+        impl<T> Default for $name<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T> Clone for $name<T> {
+            fn clone(&self) -> Self {
+                unsafe { (self.vtable.weak_clone)(self.ptr as *const ()) };
+
+                Self {
+                    ptr: self.ptr,
+                    vtable: self.vtable,
+                }
+            }
+        }
+
+        impl<T> Drop for $name<T> {
+            fn drop(&mut self) {
+                unsafe { (self.vtable.weak_drop)(self.ptr as *const ()) }
+            }
+        }
+
+        impl<T> std::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!("(", stringify!($name), ")"))
+            }
+        }
+    };
+}
+
+/// Generates type-erased versions of a [`strong_ptr`] and its [`weak_ptr`],
+/// which can be turned back into the std pointer by the binary that created them
+macro_rules! opaque_ptrs {
+    (
+        $(#[$attr:meta])*
+        $name:ident($std:ident, $vtable:ident),
+        $(#[$weak_attr:meta])*
+        weak: $weak:ident,
+        strong: $strong:ident,
+        $try_into:ident,
+        bounds: [$($bound:ident),*] $(,)?
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        pub struct $name {
+            raw: *const (),
+            vtable: &'static $vtable,
+            type_tag: $crate::type_tag::STypeTag,
+            type_name: $crate::SStr<'static>,
+        }
+
+        $(#[$weak_attr])*
+        #[repr(C)]
+        pub struct $weak {
+            raw: *const (),
+            vtable: &'static $vtable,
+            type_tag: $crate::type_tag::STypeTag,
+            type_name: $crate::SStr<'static>,
+        }
+
+        impl $name {
+            #[doc = concat!("Constructs an opaque pointer from an `", stringify!($std), "`")]
+            pub fn new<T: $($bound +)* 'static>(ptr: $std<T>) -> Self {
+                Self {
+                    raw: $std::into_raw(ptr) as *const (),
+                    vtable: &VTableOf::<T>::VTABLE,
+                    type_tag: $crate::type_tag::STypeTag::of::<T>(),
+                    type_name: $crate::SStr::from_str(std::any::type_name::<T>()),
+                }
+            }
+            #[doc = concat!("Returns the original `", stringify!($std), "` if it holds a `T` and was created in this binary")]
+            pub fn $try_into<T: 'static>(self) -> Result<$std<T>, Self> {
+                if self.type_tag != $crate::type_tag::STypeTag::of::<T>() {
+                    return Err(self);
+                }
+
+                let this = std::mem::ManuallyDrop::new(self);
+                Ok(unsafe { $std::from_raw(this.raw as *const T) })
+            }
+            /// The name of the inner type, as given by [`std::any::type_name`]
+            pub fn type_name(&self) -> &'static str {
+                self.type_name.clone().into_str()
+            }
+            pub fn strong_count(this: &Self) -> usize {
+                unsafe { (this.vtable.strong_count)(this.raw) }
+            }
+            pub fn weak_count(this: &Self) -> usize {
+                unsafe { (this.vtable.weak_count)(this.raw) }
+            }
+            pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+                this.raw == other.raw
+            }
+            pub fn downgrade(this: &Self) -> $weak {
+                $weak {
+                    raw: unsafe { (this.vtable.downgrade)(this.raw) },
+                    vtable: this.vtable,
+                    type_tag: this.type_tag,
+                    type_name: this.type_name.clone(),
+                }
+            }
+        }
+
+        impl $weak {
+            pub fn upgrade(&self) -> Option<$name> {
+                let raw = unsafe { (self.vtable.upgrade)(self.raw) };
+
+                if raw.is_null() {
+                    None
+                } else {
+                    Some($name {
+                        raw,
+                        vtable: self.vtable,
+                        type_tag: self.type_tag,
+                        type_name: self.type_name.clone(),
+                    })
+                }
+            }
+            pub fn strong_count(&self) -> usize {
+                unsafe { (self.vtable.weak_strong_count)(self.raw) }
+            }
+            pub fn weak_count(&self) -> usize {
+                unsafe { (self.vtable.weak_weak_count)(self.raw) }
+            }
+            /// The name of the inner type, as given by [`std::any::type_name`]
+            pub fn type_name(&self) -> &'static str {
+                self.type_name.clone().into_str()
+            }
+        }
+
+        impl<T: $($bound +)* 'static> From<$std<T>> for $name {
+            fn from(ptr: $std<T>) -> Self {
+                Self::new(ptr)
+            }
+        }
+
+        // A pointer created in another binary keeps working, but can't be
+        // recovered anywhere, since only its vtable knows the type it was created with.
+        impl<T: $($bound +)* 'static> From<$strong<T>> for $name {
+            fn from(ptr: $strong<T>) -> Self {
+                let ptr = std::mem::ManuallyDrop::new(ptr);
+                let type_tag = if (ptr.vtable.binary)() == $crate::type_tag::binary_id() {
+                    $crate::type_tag::STypeTag::of::<T>()
+                } else {
+                    $crate::type_tag::STypeTag::unknown()
+                };
+
+                Self {
+                    raw: ptr.ptr as *const (),
+                    vtable: ptr.vtable,
+                    type_tag,
+                    type_name: $crate::SStr::from_str(std::any::type_name::<T>()),
+                }
+            }
+        }
+
+        impl Clone for $name {
+            fn clone(&self) -> Self {
+                unsafe { (self.vtable.clone)(self.raw) };
+
+                Self {
+                    raw: self.raw,
+                    vtable: self.vtable,
+                    type_tag: self.type_tag,
+                    type_name: self.type_name.clone(),
+                }
+            }
+        }
+
+        impl Clone for $weak {
+            fn clone(&self) -> Self {
+                unsafe { (self.vtable.weak_clone)(self.raw) };
+
+                Self {
+                    raw: self.raw,
+                    vtable: self.vtable,
+                    type_tag: self.type_tag,
+                    type_name: self.type_name.clone(),
+                }
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                unsafe {
+                    (self.vtable.drop)(self.raw);
+                }
+            }
+        }
+
+        impl Drop for $weak {
+            fn drop(&mut self) {
+                unsafe {
+                    (self.vtable.weak_drop)(self.raw);
+                }
+            }
+        }
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!(stringify!($name), "<{}>"), self.type_name())
+            }
+        }
+
+        impl std::fmt::Debug for $weak {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!(stringify!($weak), "<{}>"), self.type_name())
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!("{{", stringify!($name), "}}"))
+            }
+        }
+    };
+}
+
+pub(super) use {opaque_ptrs, strong_ptr, weak_ptr};
@@ -1,5 +1,5 @@
 use super::option::SOption;
-use crate::{Immutable, Mutable};
+use crate::{FromSafe, Immutable, IntoSafe, Mutable};
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 
@@ -149,3 +149,22 @@ impl<T, E> From<SResult<T, E>> for Result<T, E> {
         r.into_result()
     }
 }
+
+impl<T: IntoSafe, E: IntoSafe> IntoSafe for Result<T, E> {
+    type Safe = SResult<T::Safe, E::Safe>;
+
+    fn into_safe(self) -> Self::Safe {
+        match self {
+            Ok(v) => SResult::Ok(v.into_safe()),
+            Err(e) => SResult::Err(e.into_safe()),
+        }
+    }
+}
+impl<T: FromSafe, E: FromSafe> FromSafe for Result<T, E> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        match safe {
+            SResult::Ok(v) => Ok(T::from_safe(v)),
+            SResult::Err(e) => Err(E::from_safe(e)),
+        }
+    }
+}
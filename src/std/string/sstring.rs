@@ -5,7 +5,8 @@ use std::{
 };
 
 use crate::{
-    sstr::SRawStr, std::prelude::SVec, Immutable, Mutable, SMutSlice, SMutStr, SSlice, SStr,
+    sstr::SRawStr, std::prelude::SVec, FromSafe, Immutable, IntoSafe, Mutable, SMutSlice, SMutStr,
+    SSlice, SStr,
 };
 
 #[cfg(feature = "convenient_methods")]
@@ -46,9 +47,9 @@ impl SString {
     }
 
     pub fn from_utf8(vec: SVec<u8>) -> Result<Self, std::string::FromUtf8Error> {
-        String::from_utf8(vec.into_vec()).map(|s| Self::from_string(s))
+        String::from_utf8(vec.into_vec()).map(Self::from_string)
     }
-    pub fn as_bytes<'a>(&'a self) -> &'a [u8] {
+    pub fn as_bytes(&self) -> &[u8] {
         self.inner.as_slice()
     }
     // TODO: as_mut_str, as_mut_vec
@@ -94,6 +95,12 @@ impl From<SString> for String {
     }
 }
 
+impl Default for SString {
+    fn default() -> Self {
+        Self::from_string(String::new())
+    }
+}
+
 impl Display for SString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         Display::fmt(&*self.as_string(), f)
@@ -119,7 +126,7 @@ impl PartialEq<str> for SString {
 
 impl Clone for SString {
     fn clone(&self) -> Self {
-        (&*self.as_string()).clone().into()
+        (*self.as_string()).clone().into()
     }
 }
 
@@ -160,4 +167,17 @@ impl DerefMut for SString {
     }
 }
 
+impl IntoSafe for String {
+    type Safe = SString;
+
+    fn into_safe(self) -> Self::Safe {
+        SString::from_string(self)
+    }
+}
+impl FromSafe for String {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_string()
+    }
+}
+
 // TODO traits
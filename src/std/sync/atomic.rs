@@ -0,0 +1,215 @@
+//! FFI-safe atomic types.
+//!
+//! See documentation of [`std::sync::atomic`]
+//!
+//! All types are `#[repr(transparent)]` wrappers of the std atomics, which have the same
+//! in-memory representation as their underlying integer (with alignment equal to the size).
+//! On all platforms where the type is lock-free this is also the layout of the C11 `_Atomic`
+//! equivalent (`_Atomic bool`, `_Atomic uint32_t`, `_Atomic(T*)`, ...), so they can be used as
+//! fields of structs shared with C code, as long as both sides use atomic operations.
+//!
+//! The whole std API (`load`, `store`, `fetch_*`, `compare_exchange`, ...) is available through [`Deref`].
+
+use crate::{FromSafe, IntoSafe};
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    sync::atomic,
+};
+
+pub use std::sync::atomic::{compiler_fence, fence, Ordering};
+
+macro_rules! atomics {
+    ($($(#[$attr:meta])* $name:ident($std:ident, $int:ty),)*) => {
+        $(
+            $(#[$attr])*
+            #[doc = concat!("FFI-safe equivalent of [`", stringify!($std), "`](atomic::", stringify!($std), ")")]
+            ///
+            #[doc = concat!("See documentation of [`std::sync::atomic::", stringify!($std), "`]")]
+            #[derive(Default)]
+            #[repr(transparent)]
+            pub struct $name(atomic::$std);
+
+            $(#[$attr])*
+            impl $name {
+                pub const fn new(v: $int) -> Self {
+                    Self(atomic::$std::new(v))
+                }
+                pub const fn from_atomic(a: atomic::$std) -> Self {
+                    Self(a)
+                }
+                pub fn into_atomic(self) -> atomic::$std {
+                    self.0
+                }
+                pub fn into_inner(self) -> $int {
+                    self.0.into_inner()
+                }
+                pub fn as_atomic(&self) -> &atomic::$std {
+                    &self.0
+                }
+            }
+
+            $(#[$attr])*
+            impl Deref for $name {
+                type Target = atomic::$std;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            $(#[$attr])*
+            impl DerefMut for $name {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.0
+                }
+            }
+
+            $(#[$attr])*
+            impl From<$int> for $name {
+                fn from(v: $int) -> Self {
+                    Self::new(v)
+                }
+            }
+
+            $(#[$attr])*
+            impl From<atomic::$std> for $name {
+                fn from(a: atomic::$std) -> Self {
+                    Self::from_atomic(a)
+                }
+            }
+
+            $(#[$attr])*
+            impl From<$name> for atomic::$std {
+                fn from(a: $name) -> Self {
+                    a.into_atomic()
+                }
+            }
+
+            $(#[$attr])*
+            impl Debug for $name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    Debug::fmt(&self.0, f)
+                }
+            }
+
+            $(#[$attr])*
+            impl IntoSafe for atomic::$std {
+                type Safe = $name;
+
+                fn into_safe(self) -> Self::Safe {
+                    $name::from_atomic(self)
+                }
+            }
+            $(#[$attr])*
+            impl FromSafe for atomic::$std {
+                fn from_safe(safe: Self::Safe) -> Self {
+                    safe.into_atomic()
+                }
+            }
+        )*
+    };
+}
+
+atomics! {
+    #[cfg(target_has_atomic = "8")]
+    SAtomicBool(AtomicBool, bool),
+    #[cfg(target_has_atomic = "32")]
+    SAtomicU32(AtomicU32, u32),
+    #[cfg(target_has_atomic = "32")]
+    SAtomicI32(AtomicI32, i32),
+    #[cfg(target_has_atomic = "64")]
+    SAtomicU64(AtomicU64, u64),
+    #[cfg(target_has_atomic = "64")]
+    SAtomicI64(AtomicI64, i64),
+    #[cfg(target_has_atomic = "ptr")]
+    SAtomicUsize(AtomicUsize, usize),
+    #[cfg(target_has_atomic = "ptr")]
+    SAtomicIsize(AtomicIsize, isize),
+}
+
+/// FFI-safe equivalent of [`AtomicPtr`](atomic::AtomicPtr)
+///
+/// See documentation of [`std::sync::atomic::AtomicPtr`]
+#[cfg(target_has_atomic = "ptr")]
+#[derive(Default)]
+#[repr(transparent)]
+pub struct SAtomicPtr<T>(atomic::AtomicPtr<T>);
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> SAtomicPtr<T> {
+    pub const fn new(p: *mut T) -> Self {
+        Self(atomic::AtomicPtr::new(p))
+    }
+    pub const fn from_atomic(a: atomic::AtomicPtr<T>) -> Self {
+        Self(a)
+    }
+    pub fn into_atomic(self) -> atomic::AtomicPtr<T> {
+        self.0
+    }
+    pub fn into_inner(self) -> *mut T {
+        self.0.into_inner()
+    }
+    pub fn as_atomic(&self) -> &atomic::AtomicPtr<T> {
+        &self.0
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> Deref for SAtomicPtr<T> {
+    type Target = atomic::AtomicPtr<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> DerefMut for SAtomicPtr<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> From<*mut T> for SAtomicPtr<T> {
+    fn from(p: *mut T) -> Self {
+        Self::new(p)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> From<atomic::AtomicPtr<T>> for SAtomicPtr<T> {
+    fn from(a: atomic::AtomicPtr<T>) -> Self {
+        Self::from_atomic(a)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> From<SAtomicPtr<T>> for atomic::AtomicPtr<T> {
+    fn from(a: SAtomicPtr<T>) -> Self {
+        a.into_atomic()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> Debug for SAtomicPtr<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> IntoSafe for atomic::AtomicPtr<T> {
+    type Safe = SAtomicPtr<T>;
+
+    fn into_safe(self) -> Self::Safe {
+        SAtomicPtr::from_atomic(self)
+    }
+}
+#[cfg(target_has_atomic = "ptr")]
+impl<T> FromSafe for atomic::AtomicPtr<T> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_atomic()
+    }
+}
@@ -1,3 +1,23 @@
+pub mod atomic;
+pub mod mpsc;
+
+mod poison;
+mod sarc;
 mod sarcopaque;
+mod scondvar;
+mod slazy;
+mod smutex;
+mod soncelock;
+mod spoison;
+mod srwlock;
+mod sweak;
 
-pub use sarcopaque::SArcOpaque;
+pub use sarc::SArc;
+pub use sarcopaque::{SArcOpaque, SWeakOpaque};
+pub use scondvar::{SCondvar, SWaitTimeoutResult};
+pub use slazy::{LazyInit, SLazy};
+pub use smutex::{SMutex, SMutexGuard};
+pub use soncelock::SOnceLock;
+pub use spoison::{SLockResult, SPoisonError, STryLockError, STryLockResult};
+pub use srwlock::{SRwLock, SRwLockReadGuard, SRwLockWriteGuard};
+pub use sweak::SWeak;
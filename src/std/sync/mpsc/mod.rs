@@ -0,0 +1,23 @@
+//! FFI-safe multi-producer, single-consumer FIFO queue communication primitives.
+//!
+//! See documentation of [`std::sync::mpsc`]
+
+mod serror;
+mod sreceiver;
+mod ssender;
+
+pub use serror::{SRecvError, SRecvTimeoutError, SSendError, STryRecvError};
+pub use sreceiver::SReceiver;
+pub use ssender::SSender;
+
+/// Creates a new asynchronous channel, returning the sender/receiver halves.
+///
+/// See documentation of [`std::sync::mpsc::channel`]
+pub fn channel<T>() -> (SSender<T>, SReceiver<T>) {
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    (
+        SSender::from_sender(sender),
+        SReceiver::from_receiver(receiver),
+    )
+}
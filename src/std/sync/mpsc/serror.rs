@@ -0,0 +1,191 @@
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+    sync::mpsc::{RecvError, RecvTimeoutError, SendError, TryRecvError},
+};
+
+/// An error returned from the [`SSender::send`](super::SSender::send) function on channels.
+///
+/// See documentation of [`std::sync::mpsc::SendError`]
+#[derive(PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+pub struct SSendError<T>(pub T);
+
+/// An error returned from the [`SReceiver::recv`](super::SReceiver::recv) function.
+///
+/// See documentation of [`std::sync::mpsc::RecvError`]
+///
+/// Unlike `RecvError` it has a (private) field, since zero-sized types are not FFI-safe.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[repr(C)]
+pub struct SRecvError {
+    _private: u8,
+}
+
+/// This enumeration is the list of the possible reasons that
+/// [`SReceiver::try_recv`](super::SReceiver::try_recv) could not return data when called.
+///
+/// See documentation of [`std::sync::mpsc::TryRecvError`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[repr(C)]
+pub enum STryRecvError {
+    Empty,
+    Disconnected,
+}
+
+/// This enumeration is the list of possible errors that made
+/// [`SReceiver::recv_timeout`](super::SReceiver::recv_timeout) unable to return data when called.
+///
+/// See documentation of [`std::sync::mpsc::RecvTimeoutError`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[repr(C)]
+pub enum SRecvTimeoutError {
+    Timeout,
+    Disconnected,
+}
+
+impl<T> SSendError<T> {
+    pub fn from_send_error(e: SendError<T>) -> Self {
+        Self(e.0)
+    }
+    pub fn into_send_error(self) -> SendError<T> {
+        SendError(self.0)
+    }
+}
+
+impl SRecvError {
+    pub const fn new() -> Self {
+        Self { _private: 0 }
+    }
+    pub fn from_recv_error(_: RecvError) -> Self {
+        Self::new()
+    }
+    pub fn into_recv_error(self) -> RecvError {
+        RecvError
+    }
+}
+
+impl STryRecvError {
+    pub fn from_try_recv_error(e: TryRecvError) -> Self {
+        match e {
+            TryRecvError::Empty => Self::Empty,
+            TryRecvError::Disconnected => Self::Disconnected,
+        }
+    }
+    pub fn into_try_recv_error(self) -> TryRecvError {
+        match self {
+            Self::Empty => TryRecvError::Empty,
+            Self::Disconnected => TryRecvError::Disconnected,
+        }
+    }
+}
+
+impl SRecvTimeoutError {
+    pub fn from_recv_timeout_error(e: RecvTimeoutError) -> Self {
+        match e {
+            RecvTimeoutError::Timeout => Self::Timeout,
+            RecvTimeoutError::Disconnected => Self::Disconnected,
+        }
+    }
+    pub fn into_recv_timeout_error(self) -> RecvTimeoutError {
+        match self {
+            Self::Timeout => RecvTimeoutError::Timeout,
+            Self::Disconnected => RecvTimeoutError::Disconnected,
+        }
+    }
+}
+
+impl<T> From<SendError<T>> for SSendError<T> {
+    fn from(e: SendError<T>) -> Self {
+        Self::from_send_error(e)
+    }
+}
+
+impl<T> From<SSendError<T>> for SendError<T> {
+    fn from(e: SSendError<T>) -> Self {
+        e.into_send_error()
+    }
+}
+
+impl From<RecvError> for SRecvError {
+    fn from(e: RecvError) -> Self {
+        Self::from_recv_error(e)
+    }
+}
+
+impl From<SRecvError> for RecvError {
+    fn from(e: SRecvError) -> Self {
+        e.into_recv_error()
+    }
+}
+
+impl From<TryRecvError> for STryRecvError {
+    fn from(e: TryRecvError) -> Self {
+        Self::from_try_recv_error(e)
+    }
+}
+
+impl From<STryRecvError> for TryRecvError {
+    fn from(e: STryRecvError) -> Self {
+        e.into_try_recv_error()
+    }
+}
+
+impl From<SRecvError> for STryRecvError {
+    fn from(_: SRecvError) -> Self {
+        Self::Disconnected
+    }
+}
+
+impl From<RecvTimeoutError> for SRecvTimeoutError {
+    fn from(e: RecvTimeoutError) -> Self {
+        Self::from_recv_timeout_error(e)
+    }
+}
+
+impl From<SRecvTimeoutError> for RecvTimeoutError {
+    fn from(e: SRecvTimeoutError) -> Self {
+        e.into_recv_timeout_error()
+    }
+}
+
+impl From<SRecvError> for SRecvTimeoutError {
+    fn from(_: SRecvError) -> Self {
+        Self::Disconnected
+    }
+}
+
+impl<T> Debug for SSendError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SSendError").finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for SSendError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&SendError(()), f)
+    }
+}
+
+impl Display for SRecvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&RecvError, f)
+    }
+}
+
+impl Display for STryRecvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.into_try_recv_error(), f)
+    }
+}
+
+impl Display for SRecvTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.into_recv_timeout_error(), f)
+    }
+}
+
+impl<T> Error for SSendError<T> {}
+impl Error for SRecvError {}
+impl Error for STryRecvError {}
+impl Error for SRecvTimeoutError {}
@@ -0,0 +1,148 @@
+use super::{SRecvError, SRecvTimeoutError, STryRecvError};
+use crate::std::{result::SResult, time::SDuration};
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError},
+};
+
+/// The receiving half of the [`channel`](super::channel) type.
+///
+/// See documentation of [`std::sync::mpsc::Receiver`]
+///
+/// The channel is owned by the binary that created it, and is only ever
+/// accessed through function pointers of that binary.
+#[repr(C)]
+pub struct SReceiver<T> {
+    raw: *mut (),
+    vtable: &'static SReceiverVTable,
+    _marker: PhantomData<T>,
+}
+
+/// The value is written to the last argument on success
+#[repr(C)]
+struct SReceiverVTable {
+    recv: unsafe extern "C" fn(*mut (), *mut ()) -> SResult<(), SRecvError>,
+    try_recv: unsafe extern "C" fn(*mut (), *mut ()) -> SResult<(), STryRecvError>,
+    recv_timeout:
+        unsafe extern "C" fn(*mut (), SDuration, *mut ()) -> SResult<(), SRecvTimeoutError>,
+    drop: unsafe extern "C" fn(*mut ()),
+}
+
+struct VTableOf<T>(PhantomData<T>);
+
+impl<T> VTableOf<T> {
+    const VTABLE: SReceiverVTable = SReceiverVTable {
+        recv: Self::recv,
+        try_recv: Self::try_recv,
+        recv_timeout: Self::recv_timeout,
+        drop: Self::drop,
+    };
+
+    unsafe fn receiver<'a>(raw: *mut ()) -> &'a Receiver<T> {
+        unsafe { &*(raw as *const Receiver<T>) }
+    }
+
+    unsafe extern "C" fn recv(raw: *mut (), out: *mut ()) -> SResult<(), SRecvError> {
+        match unsafe { Self::receiver(raw) }.recv() {
+            Ok(value) => {
+                unsafe { (out as *mut T).write(value) };
+                SResult::Ok(())
+            }
+            Err(_) => SResult::Err(SRecvError::new()),
+        }
+    }
+    unsafe extern "C" fn try_recv(raw: *mut (), out: *mut ()) -> SResult<(), STryRecvError> {
+        match unsafe { Self::receiver(raw) }.try_recv() {
+            Ok(value) => {
+                unsafe { (out as *mut T).write(value) };
+                SResult::Ok(())
+            }
+            Err(TryRecvError::Empty) => SResult::Err(STryRecvError::Empty),
+            Err(TryRecvError::Disconnected) => SResult::Err(STryRecvError::Disconnected),
+        }
+    }
+    unsafe extern "C" fn recv_timeout(
+        raw: *mut (),
+        timeout: SDuration,
+        out: *mut (),
+    ) -> SResult<(), SRecvTimeoutError> {
+        match unsafe { Self::receiver(raw) }.recv_timeout(timeout.as_duration()) {
+            Ok(value) => {
+                unsafe { (out as *mut T).write(value) };
+                SResult::Ok(())
+            }
+            Err(RecvTimeoutError::Timeout) => SResult::Err(SRecvTimeoutError::Timeout),
+            Err(RecvTimeoutError::Disconnected) => SResult::Err(SRecvTimeoutError::Disconnected),
+        }
+    }
+    unsafe extern "C" fn drop(raw: *mut ()) {
+        std::mem::drop(unsafe { Box::from_raw(raw as *mut Receiver<T>) })
+    }
+}
+
+impl<T> SReceiver<T> {
+    pub fn from_receiver(receiver: Receiver<T>) -> Self {
+        Self {
+            raw: Box::into_raw(Box::new(receiver)) as *mut (),
+            vtable: &VTableOf::<T>::VTABLE,
+            _marker: PhantomData,
+        }
+    }
+    pub fn recv(&self) -> SResult<T, SRecvError> {
+        let mut value = MaybeUninit::<T>::uninit();
+        let out = value.as_mut_ptr() as *mut ();
+
+        match unsafe { (self.vtable.recv)(self.raw, out) } {
+            SResult::Ok(()) => SResult::Ok(unsafe { value.assume_init() }),
+            SResult::Err(e) => SResult::Err(e),
+        }
+    }
+    pub fn try_recv(&self) -> SResult<T, STryRecvError> {
+        let mut value = MaybeUninit::<T>::uninit();
+        let out = value.as_mut_ptr() as *mut ();
+
+        match unsafe { (self.vtable.try_recv)(self.raw, out) } {
+            SResult::Ok(()) => SResult::Ok(unsafe { value.assume_init() }),
+            SResult::Err(e) => SResult::Err(e),
+        }
+    }
+    pub fn recv_timeout(&self, timeout: SDuration) -> SResult<T, SRecvTimeoutError> {
+        let mut value = MaybeUninit::<T>::uninit();
+        let out = value.as_mut_ptr() as *mut ();
+
+        match unsafe { (self.vtable.recv_timeout)(self.raw, timeout, out) } {
+            SResult::Ok(()) => SResult::Ok(unsafe { value.assume_init() }),
+            SResult::Err(e) => SResult::Err(e),
+        }
+    }
+    /// Returns an iterator that will block waiting for messages, until the channel is disconnected.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(move || self.recv().into_result().ok())
+    }
+    /// Returns an iterator that will yield all pending messages, without blocking.
+    pub fn try_iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(move || self.try_recv().into_result().ok())
+    }
+}
+
+impl<T> Drop for SReceiver<T> {
+    fn drop(&mut self) {
+        unsafe { (self.vtable.drop)(self.raw) }
+    }
+}
+
+unsafe impl<T: Send> Send for SReceiver<T> {}
+
+impl<T> From<Receiver<T>> for SReceiver<T> {
+    fn from(receiver: Receiver<T>) -> Self {
+        Self::from_receiver(receiver)
+    }
+}
+
+impl<T> Debug for SReceiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SReceiver").finish_non_exhaustive()
+    }
+}
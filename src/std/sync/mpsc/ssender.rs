@@ -0,0 +1,107 @@
+use super::SSendError;
+use crate::std::result::SResult;
+use std::{fmt::Debug, marker::PhantomData, mem::ManuallyDrop, sync::mpsc::Sender};
+
+/// The sending-half of the [`channel`](super::channel) type.
+///
+/// See documentation of [`std::sync::mpsc::Sender`]
+///
+/// The channel is owned by the binary that created it, and is only ever
+/// accessed through function pointers of that binary.
+#[repr(C)]
+pub struct SSender<T> {
+    raw: *mut (),
+    vtable: &'static SSenderVTable,
+    _marker: PhantomData<T>,
+}
+
+#[repr(C)]
+struct SSenderVTable {
+    /// Moves the value out of the second argument, or leaves it there
+    /// and returns `false` if the receiver was dropped
+    send: unsafe extern "C" fn(*mut (), *mut ()) -> bool,
+    clone: unsafe extern "C" fn(*mut ()) -> *mut (),
+    drop: unsafe extern "C" fn(*mut ()),
+}
+
+struct VTableOf<T>(PhantomData<T>);
+
+impl<T> VTableOf<T> {
+    const VTABLE: SSenderVTable = SSenderVTable {
+        send: Self::send,
+        clone: Self::clone,
+        drop: Self::drop,
+    };
+
+    unsafe fn sender<'a>(raw: *mut ()) -> &'a Sender<T> {
+        unsafe { &*(raw as *const Sender<T>) }
+    }
+
+    unsafe extern "C" fn send(raw: *mut (), ptr: *mut ()) -> bool {
+        let value = unsafe { (ptr as *mut T).read() };
+        match unsafe { Self::sender(raw) }.send(value) {
+            Ok(()) => true,
+            Err(e) => {
+                unsafe { (ptr as *mut T).write(e.0) };
+                false
+            }
+        }
+    }
+    unsafe extern "C" fn clone(raw: *mut ()) -> *mut () {
+        Box::into_raw(Box::new(unsafe { Self::sender(raw) }.clone())) as *mut ()
+    }
+    unsafe extern "C" fn drop(raw: *mut ()) {
+        std::mem::drop(unsafe { Box::from_raw(raw as *mut Sender<T>) })
+    }
+}
+
+impl<T> SSender<T> {
+    pub fn from_sender(sender: Sender<T>) -> Self {
+        Self {
+            raw: Box::into_raw(Box::new(sender)) as *mut (),
+            vtable: &VTableOf::<T>::VTABLE,
+            _marker: PhantomData,
+        }
+    }
+    pub fn send(&self, value: T) -> SResult<(), SSendError<T>> {
+        let mut value = ManuallyDrop::new(value);
+        let ptr = &mut *value as *mut T as *mut ();
+
+        if unsafe { (self.vtable.send)(self.raw, ptr) } {
+            SResult::Ok(())
+        } else {
+            SResult::Err(SSendError(ManuallyDrop::into_inner(value)))
+        }
+    }
+}
+
+impl<T> Clone for SSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            raw: unsafe { (self.vtable.clone)(self.raw) },
+            vtable: self.vtable,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for SSender<T> {
+    fn drop(&mut self) {
+        unsafe { (self.vtable.drop)(self.raw) }
+    }
+}
+
+unsafe impl<T: Send> Send for SSender<T> {}
+unsafe impl<T: Send> Sync for SSender<T> {}
+
+impl<T> From<Sender<T>> for SSender<T> {
+    fn from(sender: Sender<T>) -> Self {
+        Self::from_sender(sender)
+    }
+}
+
+impl<T> Debug for SSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SSender").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,169 @@
+//! `SMutex` and `SRwLock` only differ in the kind of lock, so the poisoning, and the parts
+//! of their vtables and API that don't depend on the kind of lock, are shared here.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A `std` lock allocated by the binary that created the FFI-safe lock.
+///
+/// Poisoning is tracked separately from `std`, since a panic in another binary can't
+/// be detected by the `std` of the binary that owns the lock.
+pub(super) struct RawLock<L> {
+    pub(super) lock: L,
+    poisoned: AtomicBool,
+}
+
+/// The part of a lock's vtable that doesn't depend on the kind of lock
+#[repr(C)]
+pub(super) struct PoisonVTable {
+    pub(super) is_poisoned: unsafe extern "C" fn(*mut ()) -> bool,
+    pub(super) clear_poison: unsafe extern "C" fn(*mut ()),
+    pub(super) drop: unsafe extern "C" fn(*mut ()),
+}
+
+impl<L> RawLock<L> {
+    pub(super) const VTABLE: PoisonVTable = PoisonVTable {
+        is_poisoned: Self::is_poisoned,
+        clear_poison: Self::clear_poison,
+        drop: Self::drop,
+    };
+
+    pub(super) fn new_raw(lock: L, poisoned: bool) -> *mut () {
+        Box::into_raw(Box::new(Self {
+            lock,
+            poisoned: AtomicBool::new(poisoned),
+        })) as *mut ()
+    }
+
+    pub(super) unsafe fn get<'a>(raw: *mut ()) -> &'a Self {
+        unsafe { &*(raw as *const Self) }
+    }
+
+    /// Boxes a guard of the lock, so that it can be passed to `unlock` later.
+    ///
+    /// The guard never outlives the lock, since the FFI-safe guards borrow the FFI-safe lock.
+    pub(super) fn into_guard<G>(guard: G) -> *mut () {
+        Box::into_raw(Box::new(guard)) as *mut ()
+    }
+
+    /// Drops a guard created by `into_guard`, poisoning the lock first if asked to
+    pub(super) unsafe fn unlock<G>(raw: *mut (), guard: *mut (), poison: bool) {
+        if poison {
+            unsafe { Self::get(raw) }
+                .poisoned
+                .store(true, Ordering::Relaxed);
+        }
+
+        drop(unsafe { Box::from_raw(guard as *mut G) });
+    }
+
+    unsafe extern "C" fn is_poisoned(raw: *mut ()) -> bool {
+        unsafe { Self::get(raw) }.poisoned.load(Ordering::Relaxed)
+    }
+    unsafe extern "C" fn clear_poison(raw: *mut ()) {
+        unsafe { Self::get(raw) }
+            .poisoned
+            .store(false, Ordering::Relaxed);
+    }
+    unsafe extern "C" fn drop(raw: *mut ()) {
+        drop(unsafe { Box::from_raw(raw as *mut Self) })
+    }
+}
+
+/// Kept by the guards that can poison a lock, like std's poison guard
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub(super) struct PoisonGuard {
+    /// Whether the thread was already panicking when the lock was taken,
+    /// in which case unlocking doesn't poison it
+    panicking: bool,
+}
+
+impl PoisonGuard {
+    pub(super) fn new() -> Self {
+        Self {
+            panicking: std::thread::panicking(),
+        }
+    }
+    /// Whether a panic began while the lock was held
+    pub(super) fn should_poison(&self) -> bool {
+        !self.panicking && std::thread::panicking()
+    }
+}
+
+/// Generates the methods of a lock that only deal with poisoning and the inner value.
+///
+/// The lock needs `raw`, `vtable` (with a [`PoisonVTable`] named `poison`) and `data` fields.
+macro_rules! poison_lock {
+    ($name:ident, $try_lock:ident) => {
+        impl<T> $name<T> {
+            pub fn is_poisoned(&self) -> bool {
+                unsafe { (self.vtable.poison.is_poisoned)(self.raw) }
+            }
+            pub fn clear_poison(&self) {
+                unsafe { (self.vtable.poison.clear_poison)(self.raw) }
+            }
+            pub fn get_mut(&mut self) -> $crate::std::sync::SLockResult<&mut T> {
+                let poisoned = self.is_poisoned();
+                let data = self.data.get_mut();
+
+                Self::with_poison(poisoned, data)
+            }
+            pub fn into_inner(self) -> $crate::std::sync::SLockResult<T> {
+                let poisoned = self.is_poisoned();
+                let this = std::mem::ManuallyDrop::new(self);
+                unsafe { (this.vtable.poison.drop)(this.raw) };
+                let data = unsafe { std::ptr::read(&this.data) }.into_inner();
+
+                Self::with_poison(poisoned, data)
+            }
+
+            pub(super) fn poison_result<G>(&self, guard: G) -> $crate::std::sync::SLockResult<G> {
+                Self::with_poison(self.is_poisoned(), guard)
+            }
+            fn with_poison<G>(poisoned: bool, guard: G) -> $crate::std::sync::SLockResult<G> {
+                if poisoned {
+                    $crate::std::result::SResult::Err($crate::std::sync::SPoisonError::new(guard))
+                } else {
+                    $crate::std::result::SResult::Ok(guard)
+                }
+            }
+        }
+
+        impl<T> Drop for $name<T> {
+            fn drop(&mut self) {
+                unsafe { (self.vtable.poison.drop)(self.raw) }
+            }
+        }
+
+        impl<T: Default> Default for $name<T> {
+            fn default() -> Self {
+                Self::new(T::default())
+            }
+        }
+
+        impl<T> From<T> for $name<T> {
+            fn from(value: T) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<T: std::fmt::Debug> std::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                use $crate::std::{result::SResult, sync::STryLockError};
+
+                let mut d = f.debug_struct(stringify!($name));
+                match self.$try_lock() {
+                    SResult::Ok(guard) => d.field("data", &&*guard),
+                    SResult::Err(STryLockError::Poisoned(e)) => d.field("data", &&**e.get_ref()),
+                    SResult::Err(STryLockError::WouldBlock) => {
+                        d.field("data", &format_args!("<locked>"))
+                    }
+                };
+                d.field("poisoned", &self.is_poisoned());
+                d.finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+pub(super) use poison_lock;
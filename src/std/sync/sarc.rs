@@ -0,0 +1,19 @@
+use super::SWeak;
+use crate::std::refcount::strong_ptr;
+use std::sync::{Arc, Weak};
+
+strong_ptr! {
+    /// A thread-safe reference-counting pointer.
+    ///
+    /// See documentation of [`std::sync::Arc`]
+    ///
+    /// The reference counts are managed by function pointers of the binary that
+    /// created the `Arc`, so it can be freely passed between binaries. It can only be
+    /// converted back to an `Arc` in the binary that created it.
+    SArc(Arc, SArcVTable),
+    weak: SWeak(Weak),
+    from_arc, try_into_arc, into_arc,
+}
+
+unsafe impl<T: Send + Sync> Send for SArc<T> {}
+unsafe impl<T: Send + Sync> Sync for SArc<T> {}
@@ -0,0 +1,203 @@
+use super::{SLockResult, SMutexGuard, SPoisonError};
+use crate::std::{result::SResult, time::SDuration};
+use std::{
+    fmt::Debug,
+    sync::{Condvar, Mutex, PoisonError},
+};
+
+/// A Condition Variable
+///
+/// See documentation of [`std::sync::Condvar`]
+///
+/// The condition variable never touches the mutex itself: waiting unlocks and relocks the
+/// [`SMutex`](super::SMutex) through the mutex's own vtable, so both can be created by
+/// different binaries.
+#[repr(C)]
+pub struct SCondvar {
+    raw: *mut (),
+    vtable: &'static SCondvarVTable,
+}
+
+/// A type indicating whether a timed wait on a condition variable returned due to a time out or not.
+///
+/// See documentation of [`std::sync::WaitTimeoutResult`]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[repr(C)]
+pub struct SWaitTimeoutResult {
+    timed_out: bool,
+}
+
+impl SWaitTimeoutResult {
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+}
+
+#[repr(C)]
+struct SCondvarVTable {
+    /// Returns the current generation, which every notification increments
+    generation: unsafe extern "C" fn(*mut ()) -> u64,
+    /// Blocks until the generation differs from the given one
+    wait: unsafe extern "C" fn(*mut (), u64),
+    /// Same as `wait`, returns whether it timed out
+    wait_timeout: unsafe extern "C" fn(*mut (), u64, SDuration) -> bool,
+    notify_one: unsafe extern "C" fn(*mut ()),
+    notify_all: unsafe extern "C" fn(*mut ()),
+    drop: unsafe extern "C" fn(*mut ()),
+}
+
+/// The user's mutex belongs to whichever binary created it, so the condition variable
+/// waits on its own mutex instead. The generation is read while the user's mutex is still
+/// locked, so a notification sent after it's unlocked can't be missed.
+struct RawCondvar {
+    generation: Mutex<u64>,
+    condvar: Condvar,
+}
+
+impl RawCondvar {
+    unsafe fn get<'a>(raw: *mut ()) -> &'a Self {
+        unsafe { &*(raw as *const Self) }
+    }
+    fn notify(&self, notify: fn(&Condvar)) {
+        let mut generation = self
+            .generation
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        *generation = generation.wrapping_add(1);
+        notify(&self.condvar);
+    }
+}
+
+unsafe extern "C" fn generation(raw: *mut ()) -> u64 {
+    *unsafe { RawCondvar::get(raw) }
+        .generation
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+unsafe extern "C" fn wait(raw: *mut (), generation: u64) {
+    let raw = unsafe { RawCondvar::get(raw) };
+    let guard = raw
+        .generation
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let _guard = raw
+        .condvar
+        .wait_while(guard, |current| *current == generation)
+        .unwrap_or_else(PoisonError::into_inner);
+}
+
+unsafe extern "C" fn wait_timeout(raw: *mut (), generation: u64, dur: SDuration) -> bool {
+    let raw = unsafe { RawCondvar::get(raw) };
+    let guard = raw
+        .generation
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let (_guard, result) = raw
+        .condvar
+        .wait_timeout_while(guard, dur.as_duration(), |current| *current == generation)
+        .unwrap_or_else(PoisonError::into_inner);
+
+    result.timed_out()
+}
+
+unsafe extern "C" fn notify_one(raw: *mut ()) {
+    unsafe { RawCondvar::get(raw) }.notify(Condvar::notify_one)
+}
+
+unsafe extern "C" fn notify_all(raw: *mut ()) {
+    unsafe { RawCondvar::get(raw) }.notify(Condvar::notify_all)
+}
+
+unsafe extern "C" fn drop_raw(raw: *mut ()) {
+    drop(unsafe { Box::from_raw(raw as *mut RawCondvar) })
+}
+
+static VTABLE: SCondvarVTable = SCondvarVTable {
+    generation,
+    wait,
+    wait_timeout,
+    notify_one,
+    notify_all,
+    drop: drop_raw,
+};
+
+impl SCondvar {
+    pub fn new() -> Self {
+        let raw = RawCondvar {
+            generation: Mutex::new(0),
+            condvar: Condvar::new(),
+        };
+
+        Self {
+            raw: Box::into_raw(Box::new(raw)) as *mut (),
+            vtable: &VTABLE,
+        }
+    }
+    pub fn wait<'a, T>(&self, mut guard: SMutexGuard<'a, T>) -> SLockResult<SMutexGuard<'a, T>> {
+        let generation = unsafe { (self.vtable.generation)(self.raw) };
+        guard.unlocked(|| unsafe { (self.vtable.wait)(self.raw, generation) });
+
+        guard.mutex.poison_result(guard)
+    }
+    pub fn wait_while<'a, T, F>(
+        &self,
+        mut guard: SMutexGuard<'a, T>,
+        mut condition: F,
+    ) -> SLockResult<SMutexGuard<'a, T>>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        while condition(&mut *guard) {
+            guard = match self.wait(guard) {
+                SResult::Ok(guard) => guard,
+                SResult::Err(e) => return SResult::Err(e),
+            };
+        }
+
+        SResult::Ok(guard)
+    }
+    pub fn wait_timeout<'a, T>(
+        &self,
+        mut guard: SMutexGuard<'a, T>,
+        dur: SDuration,
+    ) -> SLockResult<(SMutexGuard<'a, T>, SWaitTimeoutResult)> {
+        let generation = unsafe { (self.vtable.generation)(self.raw) };
+        let timed_out =
+            guard.unlocked(|| unsafe { (self.vtable.wait_timeout)(self.raw, generation, dur) });
+
+        let result = SWaitTimeoutResult { timed_out };
+        if guard.mutex.is_poisoned() {
+            SResult::Err(SPoisonError::new((guard, result)))
+        } else {
+            SResult::Ok((guard, result))
+        }
+    }
+    pub fn notify_one(&self) {
+        unsafe { (self.vtable.notify_one)(self.raw) }
+    }
+    pub fn notify_all(&self) {
+        unsafe { (self.vtable.notify_all)(self.raw) }
+    }
+}
+
+impl Drop for SCondvar {
+    fn drop(&mut self) {
+        unsafe { (self.vtable.drop)(self.raw) }
+    }
+}
+
+impl Default for SCondvar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for SCondvar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SCondvar").finish_non_exhaustive()
+    }
+}
+
+unsafe impl Send for SCondvar {}
+unsafe impl Sync for SCondvar {}
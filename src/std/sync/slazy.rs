@@ -0,0 +1,89 @@
+use super::SOnceLock;
+use crate::std::option::SOption;
+use std::{cell::UnsafeCell, fmt::Debug, ops::Deref};
+
+/// A value which is initialized on the first access.
+///
+/// See documentation of [`std::sync::LazyLock`]
+///
+/// The default initializer is an `extern "C"` function pointer, which keeps the type FFI-safe.
+#[repr(C)]
+pub struct SLazy<T, F = extern "C" fn() -> T> {
+    once: SOnceLock<T>,
+    init: UnsafeCell<SOption<F>>,
+}
+
+/// An initializer of [`SLazy`].
+///
+/// Implemented for closures and `extern "C"` function pointers, which don't implement [`FnOnce`].
+pub trait LazyInit<T> {
+    fn init(self) -> T;
+}
+
+impl<T, F: FnOnce() -> T> LazyInit<T> for F {
+    fn init(self) -> T {
+        self()
+    }
+}
+
+impl<T> LazyInit<T> for extern "C" fn() -> T {
+    fn init(self) -> T {
+        self()
+    }
+}
+
+impl<T, F: LazyInit<T>> SLazy<T, F> {
+    pub fn new(f: F) -> Self {
+        Self {
+            once: SOnceLock::new(),
+            init: UnsafeCell::new(SOption::Some(f)),
+        }
+    }
+    pub fn force(this: &Self) -> &T {
+        this.once.get_or_init(|| {
+            // only ever taken while holding the initialization lock
+            let init = std::mem::replace(unsafe { &mut *this.init.get() }, SOption::None);
+            match init {
+                SOption::Some(f) => f.init(),
+                SOption::None => panic!("SLazy instance has previously been poisoned"),
+            }
+        })
+    }
+    pub fn into_inner(this: Self) -> Result<T, F> {
+        let SLazy { mut once, init } = this;
+        match once.take() {
+            Some(value) => Ok(value),
+            None => match init.into_inner() {
+                SOption::Some(f) => Err(f),
+                SOption::None => panic!("SLazy instance has previously been poisoned"),
+            },
+        }
+    }
+}
+
+impl<T, F: LazyInit<T>> Deref for SLazy<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        Self::force(self)
+    }
+}
+
+impl<T: Default> Default for SLazy<T, fn() -> T> {
+    fn default() -> Self {
+        Self::new(T::default)
+    }
+}
+
+unsafe impl<T: Send + Sync, F: Send> Sync for SLazy<T, F> {}
+
+impl<T: Debug, F> Debug for SLazy<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_tuple("SLazy");
+        match self.once.get() {
+            Some(value) => d.field(value),
+            None => d.field(&format_args!("<uninit>")),
+        };
+        d.finish()
+    }
+}
@@ -0,0 +1,186 @@
+use super::{
+    poison::{poison_lock, PoisonGuard, PoisonVTable, RawLock},
+    SLockResult, STryLockError, STryLockResult,
+};
+use crate::std::result::SResult;
+use std::{
+    cell::UnsafeCell,
+    fmt::{Debug, Display},
+    ops::{Deref, DerefMut},
+    sync::{Mutex, MutexGuard, PoisonError, TryLockError},
+};
+
+/// A mutual exclusion primitive useful for protecting shared data.
+///
+/// See documentation of [`std::sync::Mutex`]
+///
+/// The lock itself is a `std` mutex allocated by the binary that created the `SMutex`,
+/// and is only ever locked and unlocked through function pointers of that binary.
+///
+/// Poisoning is tracked separately from `std`, since a panic in another binary can't
+/// be detected by the `std` of the binary that owns the lock. Like `std`, the mutex is
+/// only poisoned if a panic begins while it is locked.
+#[repr(C)]
+pub struct SMutex<T> {
+    raw: *mut (),
+    vtable: &'static SMutexVTable,
+    data: UnsafeCell<T>,
+}
+
+/// An RAII implementation of a "scoped lock" of a mutex.
+///
+/// See documentation of [`std::sync::MutexGuard`]
+#[repr(C)]
+#[must_use = "if unused the SMutex will immediately unlock"]
+pub struct SMutexGuard<'a, T> {
+    pub(super) mutex: &'a SMutex<T>,
+    guard: *mut (),
+    poison: PoisonGuard,
+}
+
+#[repr(C)]
+struct SMutexVTable {
+    /// Returns the guard, blocking until the mutex is acquired
+    lock: unsafe extern "C" fn(*mut ()) -> *mut (),
+    /// Returns null if the mutex is already locked
+    try_lock: unsafe extern "C" fn(*mut ()) -> *mut (),
+    /// Takes the guard, and whether to poison the mutex
+    unlock: unsafe extern "C" fn(*mut (), *mut (), bool),
+    poison: PoisonVTable,
+}
+
+type RawMutex = RawLock<Mutex<()>>;
+
+unsafe extern "C" fn lock(raw: *mut ()) -> *mut () {
+    let guard = unsafe { RawMutex::get(raw) }
+        .lock
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    RawMutex::into_guard(guard)
+}
+
+unsafe extern "C" fn try_lock(raw: *mut ()) -> *mut () {
+    match unsafe { RawMutex::get(raw) }.lock.try_lock() {
+        Ok(guard) => RawMutex::into_guard(guard),
+        Err(TryLockError::Poisoned(e)) => RawMutex::into_guard(e.into_inner()),
+        Err(TryLockError::WouldBlock) => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn unlock(raw: *mut (), guard: *mut (), poison: bool) {
+    unsafe { RawMutex::unlock::<MutexGuard<'static, ()>>(raw, guard, poison) }
+}
+
+static VTABLE: SMutexVTable = SMutexVTable {
+    lock,
+    try_lock,
+    unlock,
+    poison: RawMutex::VTABLE,
+};
+
+/// A copy of `VTABLE` at another address, as if another binary had created the mutex
+static FOREIGN_VTABLE: SMutexVTable = SMutexVTable {
+    lock,
+    try_lock,
+    unlock,
+    poison: RawMutex::VTABLE,
+};
+
+impl<T> SMutex<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            raw: RawMutex::new_raw(Mutex::new(()), false),
+            vtable: &VTABLE,
+            data: UnsafeCell::new(value),
+        }
+    }
+    /// Creates a mutex that behaves as if another binary had created it, for tests
+    #[doc(hidden)]
+    pub fn __new_foreign(value: T) -> Self {
+        Self {
+            raw: RawMutex::new_raw(Mutex::new(()), false),
+            vtable: &FOREIGN_VTABLE,
+            data: UnsafeCell::new(value),
+        }
+    }
+    pub fn lock(&self) -> SLockResult<SMutexGuard<'_, T>> {
+        let guard = unsafe { (self.vtable.lock)(self.raw) };
+
+        self.poison_result(SMutexGuard::new(self, guard))
+    }
+    pub fn try_lock(&self) -> STryLockResult<SMutexGuard<'_, T>> {
+        let guard = unsafe { (self.vtable.try_lock)(self.raw) };
+        if guard.is_null() {
+            return SResult::Err(STryLockError::WouldBlock);
+        }
+
+        match self.poison_result(SMutexGuard::new(self, guard)) {
+            SResult::Ok(guard) => SResult::Ok(guard),
+            SResult::Err(e) => SResult::Err(STryLockError::Poisoned(e)),
+        }
+    }
+}
+
+poison_lock!(SMutex, try_lock);
+
+unsafe impl<T: Send> Send for SMutex<T> {}
+unsafe impl<T: Send> Sync for SMutex<T> {}
+
+impl<'a, T> SMutexGuard<'a, T> {
+    fn new(mutex: &'a SMutex<T>, guard: *mut ()) -> Self {
+        Self {
+            mutex,
+            guard,
+            poison: PoisonGuard::new(),
+        }
+    }
+    /// Unlocks the mutex while `f` runs, then locks it again, so that
+    /// a condition variable of any binary can wait on it.
+    ///
+    /// `f` must not unwind, since the guard would be unlocked twice.
+    pub(super) fn unlocked<R>(&mut self, f: impl FnOnce() -> R) -> R {
+        let mutex = self.mutex;
+        unsafe { (mutex.vtable.unlock)(mutex.raw, self.guard, false) };
+        let result = f();
+        self.guard = unsafe { (mutex.vtable.lock)(mutex.raw) };
+
+        result
+    }
+}
+
+impl<T> Deref for SMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T> DerefMut for SMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<T> Drop for SMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        let poison = self.poison.should_poison();
+
+        unsafe { (self.mutex.vtable.unlock)(self.mutex.raw, self.guard, poison) }
+    }
+}
+
+unsafe impl<T: Sync> Sync for SMutexGuard<'_, T> {}
+
+impl<T: Debug> Debug for SMutexGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Display> Display for SMutexGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
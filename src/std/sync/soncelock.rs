@@ -0,0 +1,218 @@
+use std::{
+    cell::UnsafeCell,
+    fmt::Debug,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, MutexGuard, PoisonError,
+    },
+};
+
+/// A synchronization primitive which can be written to only once.
+///
+/// See documentation of [`std::sync::OnceLock`]
+///
+/// The initialization lock is owned by the binary that created the `SOnceLock`,
+/// so it can be initialized from either side of the boundary.
+#[repr(C)]
+pub struct SOnceLock<T> {
+    raw: *mut (),
+    vtable: &'static SOnceLockVTable,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+#[repr(C)]
+struct SOnceLockVTable {
+    /// Returns the guard, blocking until no other thread is initializing
+    lock: unsafe extern "C" fn(*mut ()) -> *mut (),
+    unlock: unsafe extern "C" fn(*mut ()),
+    is_completed: unsafe extern "C" fn(*mut ()) -> bool,
+    set_completed: unsafe extern "C" fn(*mut (), bool),
+    drop: unsafe extern "C" fn(*mut ()),
+}
+
+struct RawOnce {
+    mutex: Mutex<()>,
+    done: AtomicBool,
+}
+
+impl RawOnce {
+    unsafe fn get<'a>(raw: *mut ()) -> &'a Self {
+        unsafe { &*(raw as *const Self) }
+    }
+}
+
+unsafe extern "C" fn lock(raw: *mut ()) -> *mut () {
+    let guard = unsafe { RawOnce::get(raw) }
+        .mutex
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    Box::into_raw(Box::new(guard)) as *mut ()
+}
+
+unsafe extern "C" fn unlock(guard: *mut ()) {
+    drop(unsafe { Box::from_raw(guard as *mut MutexGuard<'static, ()>) });
+}
+
+unsafe extern "C" fn is_completed(raw: *mut ()) -> bool {
+    unsafe { RawOnce::get(raw) }.done.load(Ordering::Acquire)
+}
+
+unsafe extern "C" fn set_completed(raw: *mut (), done: bool) {
+    unsafe { RawOnce::get(raw) }
+        .done
+        .store(done, Ordering::Release)
+}
+
+unsafe extern "C" fn drop_raw(raw: *mut ()) {
+    drop(unsafe { Box::from_raw(raw as *mut RawOnce) })
+}
+
+static VTABLE: SOnceLockVTable = SOnceLockVTable {
+    lock,
+    unlock,
+    is_completed,
+    set_completed,
+    drop: drop_raw,
+};
+
+/// Releases the initialization lock even if the initializer panics
+struct InitGuard<'a> {
+    vtable: &'static SOnceLockVTable,
+    guard: *mut (),
+    _lock: std::marker::PhantomData<&'a ()>,
+}
+
+impl Drop for InitGuard<'_> {
+    fn drop(&mut self) {
+        unsafe { (self.vtable.unlock)(self.guard) }
+    }
+}
+
+impl<T> SOnceLock<T> {
+    pub fn new() -> Self {
+        Self {
+            raw: Box::into_raw(Box::new(RawOnce {
+                mutex: Mutex::new(()),
+                done: AtomicBool::new(false),
+            })) as *mut (),
+            vtable: &VTABLE,
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+    fn is_initialized(&self) -> bool {
+        unsafe { (self.vtable.is_completed)(self.raw) }
+    }
+    pub fn get(&self) -> Option<&T> {
+        if self.is_initialized() {
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.is_initialized() {
+            Some(unsafe { self.value.get_mut().assume_init_mut() })
+        } else {
+            None
+        }
+    }
+    pub fn set(&self, value: T) -> Result<(), T> {
+        let mut value = Some(value);
+        self.get_or_init(|| value.take().unwrap());
+
+        match value {
+            None => Ok(()),
+            Some(value) => Err(value),
+        }
+    }
+    pub fn get_or_init<F>(&self, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        let _guard = InitGuard {
+            vtable: self.vtable,
+            guard: unsafe { (self.vtable.lock)(self.raw) },
+            _lock: std::marker::PhantomData,
+        };
+
+        // another thread might have initialized it while we were waiting
+        if !self.is_initialized() {
+            let value = f();
+            unsafe { (*self.value.get()).write(value) };
+            unsafe { (self.vtable.set_completed)(self.raw, true) };
+        }
+
+        unsafe { (*self.value.get()).assume_init_ref() }
+    }
+    pub fn into_inner(mut self) -> Option<T> {
+        self.take()
+    }
+    pub fn take(&mut self) -> Option<T> {
+        if self.is_initialized() {
+            unsafe { (self.vtable.set_completed)(self.raw, false) };
+            Some(unsafe { self.value.get_mut().assume_init_read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Drop for SOnceLock<T> {
+    fn drop(&mut self) {
+        if self.is_initialized() {
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+        unsafe { (self.vtable.drop)(self.raw) }
+    }
+}
+
+unsafe impl<T: Send> Send for SOnceLock<T> {}
+unsafe impl<T: Sync + Send> Sync for SOnceLock<T> {}
+
+impl<T> Default for SOnceLock<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<T> for SOnceLock<T> {
+    fn from(value: T) -> Self {
+        let cell = Self::new();
+        let _ = cell.set(value);
+        cell
+    }
+}
+
+impl<T: Clone> Clone for SOnceLock<T> {
+    fn clone(&self) -> Self {
+        match self.get() {
+            Some(value) => Self::from(value.clone()),
+            None => Self::new(),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for SOnceLock<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: Eq> Eq for SOnceLock<T> {}
+
+impl<T: Debug> Debug for SOnceLock<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_tuple("SOnceLock");
+        match self.get() {
+            Some(value) => d.field(value),
+            None => d.field(&format_args!("<uninit>")),
+        };
+        d.finish()
+    }
+}
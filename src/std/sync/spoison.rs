@@ -0,0 +1,134 @@
+use crate::std::result::SResult;
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+    sync::{PoisonError, TryLockError},
+};
+
+/// A type of error which can be returned whenever a lock is acquired.
+///
+/// See documentation of [`std::sync::PoisonError`]
+#[repr(C)]
+pub struct SPoisonError<T> {
+    guard: T,
+}
+
+/// An enumeration of possible errors associated with a [`STryLockResult`]
+/// which can occur while trying to acquire a lock.
+///
+/// See documentation of [`std::sync::TryLockError`]
+#[repr(C)]
+pub enum STryLockError<T> {
+    Poisoned(SPoisonError<T>),
+    WouldBlock,
+}
+
+/// A type alias for the result of a lock method which can be poisoned.
+///
+/// See documentation of [`std::sync::LockResult`]
+pub type SLockResult<G> = SResult<G, SPoisonError<G>>;
+
+/// A type alias for the result of a nonblocking locking method.
+///
+/// See documentation of [`std::sync::TryLockResult`]
+pub type STryLockResult<G> = SResult<G, STryLockError<G>>;
+
+impl<T> SPoisonError<T> {
+    pub fn new(guard: T) -> Self {
+        Self { guard }
+    }
+    pub fn from_poison_error(e: PoisonError<T>) -> Self {
+        Self::new(e.into_inner())
+    }
+    pub fn into_poison_error(self) -> PoisonError<T> {
+        PoisonError::new(self.guard)
+    }
+    pub fn into_inner(self) -> T {
+        self.guard
+    }
+    pub fn get_ref(&self) -> &T {
+        &self.guard
+    }
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T> STryLockError<T> {
+    pub fn from_try_lock_error(e: TryLockError<T>) -> Self {
+        match e {
+            TryLockError::Poisoned(e) => Self::Poisoned(SPoisonError::from_poison_error(e)),
+            TryLockError::WouldBlock => Self::WouldBlock,
+        }
+    }
+    pub fn into_try_lock_error(self) -> TryLockError<T> {
+        match self {
+            Self::Poisoned(e) => TryLockError::Poisoned(e.into_poison_error()),
+            Self::WouldBlock => TryLockError::WouldBlock,
+        }
+    }
+}
+
+impl<T> From<PoisonError<T>> for SPoisonError<T> {
+    fn from(e: PoisonError<T>) -> Self {
+        Self::from_poison_error(e)
+    }
+}
+
+impl<T> From<SPoisonError<T>> for PoisonError<T> {
+    fn from(e: SPoisonError<T>) -> Self {
+        e.into_poison_error()
+    }
+}
+
+impl<T> From<SPoisonError<T>> for STryLockError<T> {
+    fn from(e: SPoisonError<T>) -> Self {
+        Self::Poisoned(e)
+    }
+}
+
+impl<T> From<TryLockError<T>> for STryLockError<T> {
+    fn from(e: TryLockError<T>) -> Self {
+        Self::from_try_lock_error(e)
+    }
+}
+
+impl<T> From<STryLockError<T>> for TryLockError<T> {
+    fn from(e: STryLockError<T>) -> Self {
+        e.into_try_lock_error()
+    }
+}
+
+impl<T> Debug for SPoisonError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SPoisonError").finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for SPoisonError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "poisoned lock: another task failed inside")
+    }
+}
+
+impl<T> Error for SPoisonError<T> {}
+
+impl<T> Debug for STryLockError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Poisoned(e) => f.debug_tuple("Poisoned").field(e).finish(),
+            Self::WouldBlock => write!(f, "WouldBlock"),
+        }
+    }
+}
+
+impl<T> Display for STryLockError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Poisoned(e) => Display::fmt(e, f),
+            Self::WouldBlock => write!(f, "try_lock failed because the operation would block"),
+        }
+    }
+}
+
+impl<T> Error for STryLockError<T> {}
@@ -0,0 +1,256 @@
+use super::{
+    poison::{poison_lock, PoisonGuard, PoisonVTable, RawLock},
+    SLockResult, STryLockError, STryLockResult,
+};
+use crate::std::result::SResult;
+use std::{
+    cell::UnsafeCell,
+    fmt::{Debug, Display},
+    ops::{Deref, DerefMut},
+    sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+};
+
+/// A reader-writer lock.
+///
+/// See documentation of [`std::sync::RwLock`]
+///
+/// Like [`SMutex`](super::SMutex), the lock is a `std` lock allocated by the binary
+/// that created the `SRwLock`, and is only used through function pointers of that binary.
+/// It is poisoned the same way, if a panic begins while it is locked for writing.
+#[repr(C)]
+pub struct SRwLock<T> {
+    raw: *mut (),
+    vtable: &'static SRwLockVTable,
+    data: UnsafeCell<T>,
+}
+
+/// RAII structure used to release the shared read access of a lock when dropped.
+///
+/// See documentation of [`std::sync::RwLockReadGuard`]
+#[repr(C)]
+#[must_use = "if unused the SRwLock will immediately unlock"]
+pub struct SRwLockReadGuard<'a, T> {
+    lock: &'a SRwLock<T>,
+    guard: *mut (),
+}
+
+/// RAII structure used to release the exclusive write access of a lock when dropped.
+///
+/// See documentation of [`std::sync::RwLockWriteGuard`]
+#[repr(C)]
+#[must_use = "if unused the SRwLock will immediately unlock"]
+pub struct SRwLockWriteGuard<'a, T> {
+    lock: &'a SRwLock<T>,
+    guard: *mut (),
+    poison: PoisonGuard,
+}
+
+#[repr(C)]
+struct SRwLockVTable {
+    /// Returns the guard, blocking until the lock is acquired
+    read: unsafe extern "C" fn(*mut ()) -> *mut (),
+    /// Returns null if the lock is held by a writer
+    try_read: unsafe extern "C" fn(*mut ()) -> *mut (),
+    /// Returns the guard, blocking until the lock is acquired
+    write: unsafe extern "C" fn(*mut ()) -> *mut (),
+    /// Returns null if the lock is held
+    try_write: unsafe extern "C" fn(*mut ()) -> *mut (),
+    unlock_read: unsafe extern "C" fn(*mut ()),
+    /// Takes the guard, and whether to poison the lock
+    unlock_write: unsafe extern "C" fn(*mut (), *mut (), bool),
+    poison: PoisonVTable,
+}
+
+type RawRwLock = RawLock<RwLock<()>>;
+
+unsafe extern "C" fn read(raw: *mut ()) -> *mut () {
+    let guard = unsafe { RawRwLock::get(raw) }
+        .lock
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    RawRwLock::into_guard(guard)
+}
+
+unsafe extern "C" fn try_read(raw: *mut ()) -> *mut () {
+    match unsafe { RawRwLock::get(raw) }.lock.try_read() {
+        Ok(guard) => RawRwLock::into_guard(guard),
+        Err(TryLockError::Poisoned(e)) => RawRwLock::into_guard(e.into_inner()),
+        Err(TryLockError::WouldBlock) => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn write(raw: *mut ()) -> *mut () {
+    let guard = unsafe { RawRwLock::get(raw) }
+        .lock
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    RawRwLock::into_guard(guard)
+}
+
+unsafe extern "C" fn try_write(raw: *mut ()) -> *mut () {
+    match unsafe { RawRwLock::get(raw) }.lock.try_write() {
+        Ok(guard) => RawRwLock::into_guard(guard),
+        Err(TryLockError::Poisoned(e)) => RawRwLock::into_guard(e.into_inner()),
+        Err(TryLockError::WouldBlock) => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn unlock_read(guard: *mut ()) {
+    drop(unsafe { Box::from_raw(guard as *mut RwLockReadGuard<'static, ()>) });
+}
+
+unsafe extern "C" fn unlock_write(raw: *mut (), guard: *mut (), poison: bool) {
+    unsafe { RawRwLock::unlock::<RwLockWriteGuard<'static, ()>>(raw, guard, poison) }
+}
+
+static VTABLE: SRwLockVTable = SRwLockVTable {
+    read,
+    try_read,
+    write,
+    try_write,
+    unlock_read,
+    unlock_write,
+    poison: RawRwLock::VTABLE,
+};
+
+impl<T> SRwLock<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            raw: RawRwLock::new_raw(RwLock::new(()), false),
+            vtable: &VTABLE,
+            data: UnsafeCell::new(value),
+        }
+    }
+    /// Moves the value out of the `RwLock` into a new `SRwLock`, keeping the poison flag
+    pub fn from_rwlock(lock: RwLock<T>) -> Self {
+        let poisoned = lock.is_poisoned();
+
+        Self {
+            raw: RawRwLock::new_raw(RwLock::new(()), poisoned),
+            vtable: &VTABLE,
+            data: UnsafeCell::new(lock.into_inner().unwrap_or_else(PoisonError::into_inner)),
+        }
+    }
+    pub fn read(&self) -> SLockResult<SRwLockReadGuard<'_, T>> {
+        let guard = SRwLockReadGuard {
+            lock: self,
+            guard: unsafe { (self.vtable.read)(self.raw) },
+        };
+
+        self.poison_result(guard)
+    }
+    pub fn try_read(&self) -> STryLockResult<SRwLockReadGuard<'_, T>> {
+        let guard = unsafe { (self.vtable.try_read)(self.raw) };
+        if guard.is_null() {
+            return SResult::Err(STryLockError::WouldBlock);
+        }
+
+        match self.poison_result(SRwLockReadGuard { lock: self, guard }) {
+            SResult::Ok(guard) => SResult::Ok(guard),
+            SResult::Err(e) => SResult::Err(STryLockError::Poisoned(e)),
+        }
+    }
+    pub fn write(&self) -> SLockResult<SRwLockWriteGuard<'_, T>> {
+        let guard = unsafe { (self.vtable.write)(self.raw) };
+
+        self.poison_result(SRwLockWriteGuard::new(self, guard))
+    }
+    pub fn try_write(&self) -> STryLockResult<SRwLockWriteGuard<'_, T>> {
+        let guard = unsafe { (self.vtable.try_write)(self.raw) };
+        if guard.is_null() {
+            return SResult::Err(STryLockError::WouldBlock);
+        }
+
+        match self.poison_result(SRwLockWriteGuard::new(self, guard)) {
+            SResult::Ok(guard) => SResult::Ok(guard),
+            SResult::Err(e) => SResult::Err(STryLockError::Poisoned(e)),
+        }
+    }
+}
+
+poison_lock!(SRwLock, try_read);
+
+unsafe impl<T: Send> Send for SRwLock<T> {}
+unsafe impl<T: Send + Sync> Sync for SRwLock<T> {}
+
+impl<T> From<RwLock<T>> for SRwLock<T> {
+    fn from(lock: RwLock<T>) -> Self {
+        Self::from_rwlock(lock)
+    }
+}
+
+impl<T> Deref for SRwLockReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T> Drop for SRwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe { (self.lock.vtable.unlock_read)(self.guard) }
+    }
+}
+
+unsafe impl<T: Sync> Sync for SRwLockReadGuard<'_, T> {}
+
+impl<T: Debug> Debug for SRwLockReadGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Display> Display for SRwLockReadGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<'a, T> SRwLockWriteGuard<'a, T> {
+    fn new(lock: &'a SRwLock<T>, guard: *mut ()) -> Self {
+        Self {
+            lock,
+            guard,
+            poison: PoisonGuard::new(),
+        }
+    }
+}
+
+impl<T> Deref for SRwLockWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T> DerefMut for SRwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T> Drop for SRwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        let poison = self.poison.should_poison();
+
+        unsafe { (self.lock.vtable.unlock_write)(self.lock.raw, self.guard, poison) }
+    }
+}
+
+unsafe impl<T: Sync> Sync for SRwLockWriteGuard<'_, T> {}
+
+impl<T: Debug> Debug for SRwLockWriteGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Display> Display for SRwLockWriteGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
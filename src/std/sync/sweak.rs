@@ -0,0 +1,14 @@
+use super::sarc::{SArc, SArcVTable, VTableOf};
+use crate::std::refcount::weak_ptr;
+use std::sync::Weak;
+
+weak_ptr! {
+    /// A version of [`SArc`] that holds a non-owning reference to the managed allocation.
+    ///
+    /// See documentation of [`std::sync::Weak`]
+    SWeak(Weak, SArcVTable),
+    strong: SArc,
+}
+
+unsafe impl<T: Send + Sync> Send for SWeak<T> {}
+unsafe impl<T: Send + Sync> Sync for SWeak<T> {}
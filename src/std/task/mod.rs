@@ -4,6 +4,8 @@
 
 mod scontext;
 mod spoll;
+mod swaker;
 
 pub use scontext::SContext;
 pub use spoll::SPoll;
+pub use swaker::SWaker;
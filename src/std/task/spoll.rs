@@ -1,4 +1,4 @@
-use crate::{Immutable, Mutable};
+use crate::{FromSafe, Immutable, IntoSafe, Mutable};
 use std::task::Poll;
 
 #[cfg(feature = "convenient_methods")]
@@ -77,3 +77,16 @@ impl<T> From<T> for SPoll<T> {
         Self::Ready(v)
     }
 }
+
+impl<T: IntoSafe> IntoSafe for Poll<T> {
+    type Safe = SPoll<T::Safe>;
+
+    fn into_safe(self) -> Self::Safe {
+        SPoll::from_poll(self.map(IntoSafe::into_safe))
+    }
+}
+impl<T: FromSafe> FromSafe for Poll<T> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_poll().map(T::from_safe)
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{FromSafe, IntoSafe};
+use std::{
+    fmt::Debug,
+    hint::unreachable_unchecked,
+    mem::{forget, ManuallyDrop},
+    task::{RawWaker, RawWakerVTable, Waker},
+};
+
+/// A handle for waking up a task by notifying its executor that it is ready to be run.
+///
+/// See documentation of [`std::task::Waker`]
+///
+/// The waker is woken, cloned and dropped by function pointers of the binary that
+/// created it, so it can be stored and woken from any binary or thread.
+#[repr(transparent)]
+pub struct SWaker {
+    raw: *const SRawWaker,
+}
+
+#[repr(C)]
+pub(super) struct SRawWaker {
+    vtable: *const VTable,
+    waker: WakerUnion,
+}
+
+union WakerUnion {
+    reference: *const Waker,
+    owned: ManuallyDrop<Waker>,
+}
+
+#[repr(C)]
+struct VTable {
+    wake_by_ref: unsafe extern "C" fn(*const SRawWaker),
+    clone: unsafe extern "C" fn(*const SRawWaker) -> *const SRawWaker,
+    wake: unsafe extern "C" fn(*const SRawWaker),
+    drop: unsafe extern "C" fn(*const SRawWaker),
+    /// Returns `false` if the second waker was created by another binary
+    will_wake: unsafe extern "C" fn(*const SRawWaker, *const SRawWaker) -> bool,
+}
+
+impl SRawWaker {
+    /// Borrows a `Waker` without taking ownership, only valid while the `Waker` is
+    pub(super) fn from_ref(waker: &Waker) -> Self {
+        Self {
+            vtable: &REF_VTABLE,
+            waker: WakerUnion {
+                reference: waker as *const _,
+            },
+        }
+    }
+    fn new_owned(waker: Waker) -> *const Self {
+        Box::into_raw(Box::new(Self {
+            vtable: &OWNED_VTABLE,
+            waker: WakerUnion {
+                owned: ManuallyDrop::new(waker),
+            },
+        }))
+    }
+    /// Returns the std waker if it was created by this binary
+    unsafe fn local_waker<'a>(waker: *const Self) -> Option<&'a Waker> {
+        let vtable = unsafe { (*waker).vtable };
+
+        if std::ptr::eq(vtable, &REF_VTABLE) {
+            Some(unsafe { &*(*waker).waker.reference })
+        } else if std::ptr::eq(vtable, &OWNED_VTABLE) {
+            Some(unsafe { &(*waker).waker.owned })
+        } else {
+            None
+        }
+    }
+}
+
+unsafe extern "C" fn will_wake(waker: *const SRawWaker, other: *const SRawWaker) -> bool {
+    match unsafe { (SRawWaker::local_waker(waker), SRawWaker::local_waker(other)) } {
+        (Some(waker), Some(other)) => waker.will_wake(other),
+        _ => false,
+    }
+}
+
+static REF_VTABLE: VTable = {
+    unsafe extern "C" fn wake_by_ref(waker: *const SRawWaker) {
+        // Since this is the Ref variant of the vtable
+        // we can assume that the union is reference (not owned)
+        unsafe { (*(*waker).waker.reference).wake_by_ref() }
+    }
+    unsafe extern "C" fn clone(waker: *const SRawWaker) -> *const SRawWaker {
+        // Since this is the Ref variant of the vtable
+        // we can assume that the union is reference (not owned)
+        SRawWaker::new_owned(unsafe { (*(*waker).waker.reference).clone() })
+    }
+    unsafe extern "C" fn wake(_: *const SRawWaker) {
+        // Since this is the Ref variant of the vtable
+        // this method will never be called, since it takes Waker by value
+        unsafe { unreachable_unchecked() }
+    }
+    unsafe extern "C" fn drop(_: *const SRawWaker) {
+        // Since this is the Ref variant of the vtable
+        // this method will never be called, since it takes Waker by value
+        unsafe { unreachable_unchecked() }
+    }
+
+    VTable {
+        wake_by_ref,
+        clone,
+        wake,
+        drop,
+        will_wake,
+    }
+};
+
+static OWNED_VTABLE: VTable = {
+    unsafe extern "C" fn wake_by_ref(waker: *const SRawWaker) {
+        // Since this is the Owned variant of the vtable
+        // we can assume that the union is owned (not reference)
+        unsafe { (*(*waker).waker.owned).wake_by_ref() }
+    }
+    unsafe extern "C" fn clone(waker: *const SRawWaker) -> *const SRawWaker {
+        // Since this is the Owned variant of the vtable
+        // we can assume that the union is owned (not reference)
+        SRawWaker::new_owned(unsafe { (*(*waker).waker.owned).clone() })
+    }
+    unsafe extern "C" fn wake(waker: *const SRawWaker) {
+        unsafe {
+            let waker = Box::from_raw(waker as *mut SRawWaker);
+            // Since this is the Owned variant of the vtable
+            // we can assume that the union is owned (not reference)
+            ManuallyDrop::into_inner(waker.waker.owned).wake();
+        }
+    }
+    unsafe extern "C" fn drop(waker: *const SRawWaker) {
+        unsafe {
+            let waker = Box::from_raw(waker as *mut SRawWaker);
+            // Since this is the Owned variant of the vtable
+            // we can assume that the union is owned (not reference)
+            ManuallyDrop::into_inner(waker.waker.owned);
+        }
+    }
+
+    VTable {
+        wake_by_ref,
+        clone,
+        wake,
+        drop,
+        will_wake,
+    }
+};
+
+pub(super) static RUST_RAWWAKER_VTABLE: RawWakerVTable = {
+    unsafe fn clone(ptr: *const ()) -> RawWaker {
+        let waker = ptr as *const SRawWaker;
+
+        let cloned = unsafe { ((*(*waker).vtable).clone)(waker) };
+
+        RawWaker::new(cloned.cast(), &RUST_RAWWAKER_VTABLE)
+    }
+    unsafe fn wake(ptr: *const ()) {
+        let waker = ptr as *const SRawWaker;
+
+        unsafe { ((*(*waker).vtable).wake)(waker) }
+    }
+    unsafe fn wake_by_ref(ptr: *const ()) {
+        let waker = ptr as *const SRawWaker;
+
+        unsafe { ((*(*waker).vtable).wake_by_ref)(waker) }
+    }
+    unsafe fn drop(ptr: *const ()) {
+        let waker = ptr as *const SRawWaker;
+
+        unsafe { ((*(*waker).vtable).drop)(waker) }
+    }
+
+    RawWakerVTable::new(clone, wake, wake_by_ref, drop)
+};
+
+impl SWaker {
+    /// Borrows the raw waker as an `SWaker`, which must never be dropped or woken by value
+    pub(super) unsafe fn from_raw_ref(raw: &*const SRawWaker) -> &Self {
+        // SWaker is a transparent wrapper of the pointer
+        unsafe { &*(raw as *const *const SRawWaker as *const Self) }
+    }
+    /// A `Waker` that wraps an `SWaker` (for example one from [`into_waker`](Self::into_waker))
+    /// is unwrapped instead of wrapped again.
+    pub fn from_waker(waker: Waker) -> Self {
+        if std::ptr::eq(waker.vtable(), &RUST_RAWWAKER_VTABLE) {
+            let raw = waker.data() as *const SRawWaker;
+            forget(waker);
+
+            return Self { raw };
+        }
+
+        Self {
+            raw: SRawWaker::new_owned(waker),
+        }
+    }
+    /// An `SWaker` created by this binary gives back the original `Waker`.
+    pub fn into_waker(self) -> Waker {
+        let this = ManuallyDrop::new(self);
+
+        if std::ptr::eq(unsafe { (*this.raw).vtable }, &OWNED_VTABLE) {
+            let raw = unsafe { Box::from_raw(this.raw as *mut SRawWaker) };
+            // Since this is the Owned variant of the vtable
+            // we can assume that the union is owned (not reference)
+            return ManuallyDrop::into_inner(unsafe { raw.waker.owned });
+        }
+
+        unsafe { Waker::from_raw(RawWaker::new(this.raw.cast(), &RUST_RAWWAKER_VTABLE)) }
+    }
+    /// Wakes up the task associated with this waker.
+    pub fn wake(self) {
+        let this = ManuallyDrop::new(self);
+
+        unsafe { ((*(*this.raw).vtable).wake)(this.raw) }
+    }
+    /// Wakes up the task associated with this waker without consuming it.
+    pub fn wake_by_ref(&self) {
+        unsafe { ((*(*self.raw).vtable).wake_by_ref)(self.raw) }
+    }
+    /// Returns `true` if this waker and the other waker would awake the same task.
+    ///
+    /// This is best-effort like [`Waker::will_wake`], wakers created by
+    /// different binaries always return `false`.
+    pub fn will_wake(&self, other: &SWaker) -> bool {
+        std::ptr::eq(self.raw, other.raw)
+            || unsafe { ((*(*self.raw).vtable).will_wake)(self.raw, other.raw) }
+    }
+}
+
+impl Clone for SWaker {
+    fn clone(&self) -> Self {
+        Self {
+            raw: unsafe { ((*(*self.raw).vtable).clone)(self.raw) },
+        }
+    }
+}
+
+impl Drop for SWaker {
+    fn drop(&mut self) {
+        unsafe { ((*(*self.raw).vtable).drop)(self.raw) }
+    }
+}
+
+unsafe impl Send for SWaker {}
+unsafe impl Sync for SWaker {}
+
+impl From<Waker> for SWaker {
+    fn from(waker: Waker) -> Self {
+        Self::from_waker(waker)
+    }
+}
+
+impl From<SWaker> for Waker {
+    fn from(waker: SWaker) -> Self {
+        waker.into_waker()
+    }
+}
+
+impl Debug for SWaker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SWaker")
+            .field("raw", &self.raw)
+            .finish_non_exhaustive()
+    }
+}
+
+impl IntoSafe for Waker {
+    type Safe = SWaker;
+
+    fn into_safe(self) -> Self::Safe {
+        SWaker::from_waker(self)
+    }
+}
+impl FromSafe for Waker {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_waker()
+    }
+}
@@ -0,0 +1,53 @@
+mod sjoinhandle;
+mod sthread;
+mod sthreadid;
+
+pub use sjoinhandle::SJoinHandle;
+pub use sthread::SThread;
+pub use sthreadid::SThreadId;
+
+use crate::std::{panic::ffi_catch_unwind, time::SDuration};
+use std::panic::AssertUnwindSafe;
+
+/// Spawns a new thread, returning an [`SJoinHandle`] for it.
+///
+/// See documentation of [`std::thread::spawn`]
+///
+/// The closure runs inside [`ffi_catch_unwind`], so a panic is returned
+/// from [`SJoinHandle::join`] with its location.
+pub fn spawn<F, T>(f: F) -> SJoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    // numbered here, so that the handle agrees with the thread even if it exits right away
+    let id = SThreadId::next();
+    let handle = std::thread::spawn(move || {
+        id.register();
+        // the panic is only observed through the join handle, like in std
+        ffi_catch_unwind(AssertUnwindSafe(f))
+    });
+
+    SJoinHandle::from_caught(handle, id)
+}
+
+/// Gets a handle to the thread that invokes it.
+///
+/// See documentation of [`std::thread::current`]
+pub fn current() -> SThread {
+    SThread::current()
+}
+
+/// Blocks unless or until the current thread's token is made available.
+///
+/// See documentation of [`std::thread::park`]
+pub fn park() {
+    std::thread::park()
+}
+
+/// Puts the current thread to sleep for at least the specified amount of time.
+///
+/// See documentation of [`std::thread::sleep`]
+pub fn sleep(dur: SDuration) {
+    std::thread::sleep(dur.as_duration())
+}
@@ -0,0 +1,163 @@
+use super::{sthreadid, SThread, SThreadId};
+use crate::std::{panic::SPanicInfo, result::SResult};
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    thread::{JoinHandle, Thread},
+};
+
+/// An owned permission to join on a thread (block on its termination).
+///
+/// See documentation of [`std::thread::JoinHandle`]
+///
+/// The thread is joined by the binary that spawned it, so the handle can be
+/// passed to another binary, for example to be joined when a plugin is unloaded.
+/// Dropping the handle detaches the thread.
+#[repr(C)]
+pub struct SJoinHandle<T> {
+    raw: *mut (),
+    vtable: &'static SJoinHandleVTable,
+    thread: SThread,
+    _marker: PhantomData<T>,
+}
+
+#[repr(C)]
+struct SJoinHandleVTable {
+    /// Consumes the handle, writing `SResult<T, SPanicInfo>` to the second argument
+    join: unsafe extern "C" fn(*mut (), *mut ()),
+    is_finished: unsafe extern "C" fn(*mut ()) -> bool,
+    drop: unsafe extern "C" fn(*mut ()),
+}
+
+/// The kinds of std handles that can be behind an `SJoinHandle`
+trait Handle<T> {
+    fn join(self) -> SResult<T, SPanicInfo>;
+    fn is_finished(&self) -> bool;
+    fn thread(&self) -> &Thread;
+}
+
+/// A thread spawned by [`spawn`](super::spawn), which catches its own panics
+struct Caught<T>(JoinHandle<SResult<T, SPanicInfo>>);
+
+/// Any other thread, only the panic message survives
+struct Plain<T>(JoinHandle<T>);
+
+impl<T> Handle<T> for Caught<T> {
+    fn join(self) -> SResult<T, SPanicInfo> {
+        match self.0.join() {
+            Ok(result) => result,
+            Err(payload) => SResult::Err(SPanicInfo::from_payload(&*payload, None)),
+        }
+    }
+    fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+    fn thread(&self) -> &Thread {
+        self.0.thread()
+    }
+}
+
+impl<T> Handle<T> for Plain<T> {
+    fn join(self) -> SResult<T, SPanicInfo> {
+        match self.0.join() {
+            Ok(v) => SResult::Ok(v),
+            Err(payload) => SResult::Err(SPanicInfo::from_payload(&*payload, None)),
+        }
+    }
+    fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+    fn thread(&self) -> &Thread {
+        self.0.thread()
+    }
+}
+
+struct VTableOf<H, T>(PhantomData<(H, T)>);
+
+impl<H: Handle<T>, T> VTableOf<H, T> {
+    const VTABLE: SJoinHandleVTable = SJoinHandleVTable {
+        join: Self::join,
+        is_finished: Self::is_finished,
+        drop: Self::drop,
+    };
+
+    fn into_handle(handle: H, thread: SThread) -> SJoinHandle<T> {
+        SJoinHandle {
+            thread,
+            raw: Box::into_raw(Box::new(handle)) as *mut (),
+            vtable: &Self::VTABLE,
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe extern "C" fn join(raw: *mut (), out: *mut ()) {
+        let handle = unsafe { Box::from_raw(raw as *mut H) };
+        let thread = handle.thread().id();
+        unsafe { (out as *mut SResult<T, SPanicInfo>).write(handle.join()) };
+        sthreadid::forget(thread);
+    }
+    unsafe extern "C" fn is_finished(raw: *mut ()) -> bool {
+        unsafe { &*(raw as *const H) }.is_finished()
+    }
+    unsafe extern "C" fn drop(raw: *mut ()) {
+        std::mem::drop(unsafe { Box::from_raw(raw as *mut H) })
+    }
+}
+
+impl<T> SJoinHandle<T> {
+    /// `id` is the id the thread registered before running anything
+    pub(super) fn from_caught(handle: JoinHandle<SResult<T, SPanicInfo>>, id: SThreadId) -> Self {
+        let thread = SThread::with_id(handle.thread().clone(), id);
+
+        VTableOf::<Caught<T>, T>::into_handle(Caught(handle), thread)
+    }
+    /// Panics of threads not spawned with [`spawn`](super::spawn) lose their location.
+    ///
+    /// The thread is numbered now, so if it already exited after asking for its own id,
+    /// the handle gets a new one.
+    pub fn from_join_handle(handle: JoinHandle<T>) -> Self {
+        let thread = SThread::from_thread(handle.thread().clone());
+
+        VTableOf::<Plain<T>, T>::into_handle(Plain(handle), thread)
+    }
+    /// Waits for the associated thread to finish.
+    ///
+    /// Returns the panic if the thread panicked.
+    pub fn join(self) -> SResult<T, SPanicInfo> {
+        let mut result = MaybeUninit::<SResult<T, SPanicInfo>>::uninit();
+        let this = std::mem::ManuallyDrop::new(self);
+
+        unsafe { (this.vtable.join)(this.raw, result.as_mut_ptr() as *mut ()) };
+        drop(unsafe { std::ptr::read(&this.thread) });
+
+        unsafe { result.assume_init() }
+    }
+    pub fn is_finished(&self) -> bool {
+        unsafe { (self.vtable.is_finished)(self.raw) }
+    }
+    pub fn thread(&self) -> &SThread {
+        &self.thread
+    }
+}
+
+impl<T> Drop for SJoinHandle<T> {
+    fn drop(&mut self) {
+        unsafe { (self.vtable.drop)(self.raw) }
+    }
+}
+
+unsafe impl<T> Send for SJoinHandle<T> {}
+unsafe impl<T> Sync for SJoinHandle<T> {}
+
+impl<T> From<JoinHandle<T>> for SJoinHandle<T> {
+    fn from(handle: JoinHandle<T>) -> Self {
+        Self::from_join_handle(handle)
+    }
+}
+
+impl<T> Debug for SJoinHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SJoinHandle").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,113 @@
+use super::SThreadId;
+use crate::{std::option::SOption, SStr};
+use std::{fmt::Debug, thread::Thread};
+
+/// A handle to a thread.
+///
+/// See documentation of [`std::thread::Thread`]
+///
+/// The handle carries the id of the thread, so it stays the same after the thread exits.
+#[repr(C)]
+pub struct SThread {
+    raw: *mut (),
+    vtable: &'static SThreadVTable,
+    id: SThreadId,
+}
+
+#[repr(C)]
+struct SThreadVTable {
+    clone: unsafe extern "C" fn(*mut ()) -> *mut (),
+    unpark: unsafe extern "C" fn(*mut ()),
+    /// The string lives as long as the handle
+    name: unsafe extern "C" fn(*mut ()) -> SOption<SStr<'static>>,
+    drop: unsafe extern "C" fn(*mut ()),
+}
+
+unsafe fn thread<'a>(raw: *mut ()) -> &'a Thread {
+    unsafe { &*(raw as *const Thread) }
+}
+
+unsafe extern "C" fn clone(raw: *mut ()) -> *mut () {
+    Box::into_raw(Box::new(unsafe { thread(raw) }.clone())) as *mut ()
+}
+
+unsafe extern "C" fn unpark(raw: *mut ()) {
+    unsafe { thread(raw) }.unpark()
+}
+
+unsafe extern "C" fn name(raw: *mut ()) -> SOption<SStr<'static>> {
+    SOption::from_option(unsafe { thread(raw) }.name().map(SStr::from_str))
+}
+
+unsafe extern "C" fn drop_raw(raw: *mut ()) {
+    drop(unsafe { Box::from_raw(raw as *mut Thread) })
+}
+
+static VTABLE: SThreadVTable = SThreadVTable {
+    clone,
+    unpark,
+    name,
+    drop: drop_raw,
+};
+
+impl SThread {
+    pub fn from_thread(thread: Thread) -> Self {
+        let id = SThreadId::from_thread_id(thread.id());
+
+        Self::with_id(thread, id)
+    }
+    pub(super) fn with_id(thread: Thread, id: SThreadId) -> Self {
+        Self {
+            raw: Box::into_raw(Box::new(thread)) as *mut (),
+            vtable: &VTABLE,
+            id,
+        }
+    }
+    /// Gets a handle to the thread that invokes it.
+    pub fn current() -> Self {
+        Self::from_thread(std::thread::current())
+    }
+    pub fn unpark(&self) {
+        unsafe { (self.vtable.unpark)(self.raw) }
+    }
+    pub fn name(&self) -> SOption<SStr<'_>> {
+        unsafe { (self.vtable.name)(self.raw) }
+    }
+    pub fn id(&self) -> SThreadId {
+        self.id
+    }
+}
+
+impl Clone for SThread {
+    fn clone(&self) -> Self {
+        Self {
+            raw: unsafe { (self.vtable.clone)(self.raw) },
+            vtable: self.vtable,
+            id: self.id,
+        }
+    }
+}
+
+impl Drop for SThread {
+    fn drop(&mut self) {
+        unsafe { (self.vtable.drop)(self.raw) }
+    }
+}
+
+unsafe impl Send for SThread {}
+unsafe impl Sync for SThread {}
+
+impl From<Thread> for SThread {
+    fn from(thread: Thread) -> Self {
+        Self::from_thread(thread)
+    }
+}
+
+impl Debug for SThread {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SThread")
+            .field("id", &self.id())
+            .field("name", &self.name().as_ref().map(|n| n.as_str()))
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,145 @@
+use crate::type_tag::binary_id;
+use std::{
+    cell::OnceCell,
+    collections::HashMap,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard,
+    },
+    thread::ThreadId,
+};
+
+/// A unique identifier for a running thread.
+///
+/// See documentation of [`std::thread::ThreadId`]
+///
+/// Ids are handed out by each binary with its own copy of this crate, so they also
+/// record the binary that numbered the thread, and ids from different binaries never
+/// compare equal. The id of an [`SThread`](super::SThread) is numbered by the binary that
+/// created the handle, when it's created.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct SThreadId {
+    id: u64,
+    binary: *const u8,
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The numbers of the threads that asked for their own id, and of the threads
+/// that were numbered by another thread before they did.
+///
+/// A thread removes its entry when it exits, and joining an [`SJoinHandle`](super::SJoinHandle)
+/// removes the entry of its thread, so only detached threads that never ask for their own id
+/// stay in the map. Once the entry is gone, the number is only remembered by the ids and
+/// [`SThread`](super::SThread) handles taken before.
+static IDS: Mutex<Option<HashMap<ThreadId, u64>>> = Mutex::new(None);
+
+/// The entry of the current thread in `IDS`, removed when the thread exits
+struct Registration {
+    id: ThreadId,
+    number: u64,
+}
+
+thread_local! {
+    /// Spares the current thread the lock in the common case
+    static CURRENT: OnceCell<Registration> = const { OnceCell::new() };
+}
+
+fn ids() -> MutexGuard<'static, Option<HashMap<ThreadId, u64>>> {
+    IDS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn next_number() -> u64 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+fn lookup(id: ThreadId) -> u64 {
+    *ids()
+        .get_or_insert_with(HashMap::new)
+        .entry(id)
+        .or_insert_with(next_number)
+}
+
+/// Removes the entry of a thread that has exited
+pub(super) fn forget(id: ThreadId) {
+    if let Some(ids) = ids().as_mut() {
+        ids.remove(&id);
+    }
+}
+
+impl Registration {
+    fn current() -> Self {
+        let id = std::thread::current().id();
+
+        Self {
+            id,
+            number: lookup(id),
+        }
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        forget(self.id)
+    }
+}
+
+impl SThreadId {
+    pub fn from_thread_id(id: ThreadId) -> Self {
+        let current = CURRENT.try_with(|current| {
+            let current = current.get_or_init(Registration::current);
+            (current.id == id).then_some(current.number)
+        });
+
+        match current {
+            Ok(Some(number)) => Self::new(number),
+            _ => Self::new(lookup(id)),
+        }
+    }
+    /// The number of this thread, unique among the threads numbered by the same binary
+    pub fn as_u64(&self) -> u64 {
+        self.id
+    }
+
+    fn new(id: u64) -> Self {
+        Self {
+            id,
+            binary: binary_id(),
+        }
+    }
+    /// Numbers a thread before it's spawned, which then has to [`register`](Self::register) it
+    pub(super) fn next() -> Self {
+        Self::new(next_number())
+    }
+    /// Makes this the id of the current thread, which must not have been numbered yet
+    pub(super) fn register(self) {
+        let _ = CURRENT.try_with(|current| {
+            current.get_or_init(|| {
+                let id = std::thread::current().id();
+                ids().get_or_insert_with(HashMap::new).insert(id, self.id);
+
+                Registration {
+                    id,
+                    number: self.id,
+                }
+            });
+        });
+    }
+}
+
+impl From<ThreadId> for SThreadId {
+    fn from(id: ThreadId) -> Self {
+        Self::from_thread_id(id)
+    }
+}
+
+unsafe impl Send for SThreadId {}
+unsafe impl Sync for SThreadId {}
+
+impl Debug for SThreadId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SThreadId").field(&self.id).finish()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{FromSafe, IntoSafe};
 use std::time::Duration;
 
 /// A Duration type to represent a span of time, typically used for system timeouts.
@@ -35,4 +36,17 @@ impl From<SDuration> for Duration {
     }
 }
 
+impl IntoSafe for Duration {
+    type Safe = SDuration;
+
+    fn into_safe(self) -> Self::Safe {
+        SDuration::from_duration(self)
+    }
+}
+impl FromSafe for Duration {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.as_duration()
+    }
+}
+
 // todo more trait impls
@@ -1,9 +1,10 @@
-use crate::{Immutable, Mutable};
+use crate::{sslice::SRawSlice, FromSafe, Immutable, IntoSafe, Mutable};
 use core::slice;
 use std::{
+    borrow::{Borrow, BorrowMut},
     fmt::Debug,
     mem::forget,
-    ops::{Index, IndexMut},
+    ops::{Deref, DerefMut, Index, IndexMut},
 };
 
 #[cfg(feature = "convenient_methods")]
@@ -62,6 +63,9 @@ impl<T> SVec<T> {
     pub fn len(&self) -> usize {
         self.length
     }
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         unsafe { slice::from_raw_parts_mut(self.ptr, self.length) }
     }
@@ -80,7 +84,6 @@ impl<T> SVec<T> {
 	    fn dedup_by_key<F, K>(&mut self, key: F) where F: FnMut(&mut T) -> K, K: PartialEq<K>;
 	    fn insert(&mut self, index: usize, element: T);
 	    fn into_boxed_slice(self) -> Box<[T]>;
-	    fn is_empty(&self) -> bool;
 	    fn leak<'a>(self) -> &'a mut [T];
 	    fn pop(&mut self) -> Option<T>;
 	    fn push(&mut self, value: T);
@@ -114,6 +117,20 @@ impl<T: Clone> SVec<T> {
     ]);
 }
 
+impl<T> Default for SVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for SVec<T> {
+    fn drop(&mut self) {
+        unsafe {
+            drop(Vec::from_raw_parts(self.ptr, self.length, self.capacity));
+        }
+    }
+}
+
 impl<T> From<Vec<T>> for SVec<T> {
     fn from(v: Vec<T>) -> Self {
         Self::from_vec(v)
@@ -146,6 +163,32 @@ impl<T: PartialEq> PartialEq for SVec<T> {
     }
 }
 
+impl<T> Borrow<SRawSlice<T>> for SVec<T> {
+    fn borrow(&self) -> &SRawSlice<T> {
+        SRawSlice::from_slice(self.as_slice())
+    }
+}
+
+impl<T> BorrowMut<SRawSlice<T>> for SVec<T> {
+    fn borrow_mut(&mut self) -> &mut SRawSlice<T> {
+        SRawSlice::from_mut_slice(self.as_mut_slice())
+    }
+}
+
+impl<T> Deref for SVec<T> {
+    type Target = SRawSlice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        SRawSlice::from_slice(self.as_slice())
+    }
+}
+
+impl<T> DerefMut for SVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        SRawSlice::from_mut_slice(self.as_mut_slice())
+    }
+}
+
 impl<T> Index<usize> for SVec<T> {
     type Output = T;
 
@@ -166,6 +209,19 @@ impl<'a, T> IntoIterator for &'a SVec<T> {
     type IntoIter = std::slice::Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.as_slice().into_iter()
+        self.as_slice().iter()
+    }
+}
+
+impl<T: IntoSafe> IntoSafe for Vec<T> {
+    type Safe = SVec<T::Safe>;
+
+    fn into_safe(self) -> Self::Safe {
+        SVec::from_vec(self.into_iter().map(IntoSafe::into_safe).collect())
+    }
+}
+impl<T: FromSafe> FromSafe for Vec<T> {
+    fn from_safe(safe: Self::Safe) -> Self {
+        safe.into_vec().into_iter().map(T::from_safe).collect()
     }
 }
@@ -1,21 +1,41 @@
 use std::fmt::Debug;
 
-/// `#[repr(C)]` version of `(A, B)`
-#[repr(C)]
-pub struct STuple2<A, B>(pub A, pub B);
+macro_rules! tuples {
+    ($($name:ident($($t:ident $i:tt),+);)*) => {
+        $(
+            #[doc = concat!("`#[repr(C)]` version of `(", stringify!($($t),+), ")`")]
+            #[repr(C)]
+            pub struct $name<$($t),+>($(pub $t),+);
 
-impl<A: PartialEq, B: PartialEq> PartialEq for STuple2<A, B> {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0 && self.1 == self.1
-    }
+            impl<$($t: PartialEq),+> PartialEq for $name<$($t),+> {
+                fn eq(&self, other: &Self) -> bool {
+                    $(self.$i == other.$i)&&+
+                }
+            }
+            impl<$($t: Clone),+> Clone for $name<$($t),+> {
+                fn clone(&self) -> Self {
+                    Self($(self.$i.clone()),+)
+                }
+            }
+            impl<$($t: Debug),+> Debug for $name<$($t),+> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    Debug::fmt(&($(&self.$i,)+), f)
+                }
+            }
+        )*
+    };
 }
-impl<A: Clone, B: Clone> Clone for STuple2<A, B> {
-    fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone())
-    }
-}
-impl<A: Debug, B: Debug> Debug for STuple2<A, B> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Debug::fmt(&(&self.0, &self.1), f)
-    }
+
+tuples! {
+    STuple2(A 0, B 1);
+    STuple3(A 0, B 1, C 2);
+    STuple4(A 0, B 1, C 2, D 3);
+    STuple5(A 0, B 1, C 2, D 3, E 4);
+    STuple6(A 0, B 1, C 2, D 3, E 4, F 5);
+    STuple7(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    STuple8(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    STuple9(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    STuple10(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    STuple11(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    STuple12(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
 }
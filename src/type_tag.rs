@@ -0,0 +1,51 @@
+use std::{
+    any::TypeId,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+/// Identifies a type within the binary that created the tag.
+///
+/// `TypeId`s can only be compared within a single compilation, so the tag
+/// also stores the address of a static that every copy of this crate has its own of.
+/// Tags created in different binaries never compare equal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(C)]
+pub(crate) struct STypeTag {
+    binary: *const u8,
+    type_id: u64,
+}
+
+static BINARY: u8 = 0;
+
+/// Identifies the binary that calls it, for types that only need to know
+/// whether they were created in the current binary.
+pub(crate) extern "C" fn binary_id() -> *const u8 {
+    &BINARY
+}
+
+/// Stands in for the id of another binary, for testing values created elsewhere
+pub(crate) extern "C" fn foreign_binary_id() -> *const u8 {
+    static FOREIGN: u8 = 0;
+    &FOREIGN
+}
+
+impl STypeTag {
+    pub(crate) fn of<T: ?Sized + 'static>() -> Self {
+        let mut hasher = DefaultHasher::new();
+        TypeId::of::<T>().hash(&mut hasher);
+
+        Self {
+            binary: &BINARY,
+            type_id: hasher.finish(),
+        }
+    }
+    /// A tag that matches no type in any binary, for values whose type
+    /// can only be identified by the binary that created them
+    pub(crate) fn unknown() -> Self {
+        Self {
+            binary: std::ptr::null(),
+            type_id: 0,
+        }
+    }
+}
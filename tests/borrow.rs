@@ -0,0 +1,35 @@
+use safe_types::{std::borrow::SCow, SRawSlice, SSlice};
+use std::borrow::Cow;
+
+#[test]
+fn slice_cow() {
+    let data = [1u8, 2, 3];
+
+    let borrowed: SCow<SSlice<u8>> = SCow::Borrowed(SSlice::from_slice(&data));
+    assert_eq!(&**borrowed, &data);
+
+    let owned = SCow::<SSlice<u8>>::from_cow(Cow::Owned(SRawSlice::from_slice(&data).to_owned()));
+    assert_eq!(owned.clone().into_cow().len(), 3);
+    assert!(matches!(owned, SCow::Owned(v) if v.as_slice() == data));
+}
+
+#[test]
+fn str_cow() {
+    use safe_types::{std::string::SString, SStr};
+
+    let mut c: SCow<SStr> = "abc".into();
+    assert!(c.is_borrowed());
+    assert_eq!(format!("{} {:?}", c, c), "abc \"abc\"");
+
+    c += "def";
+    assert!(c.is_owned());
+    assert_eq!(c, SCow::<SStr>::from(String::from("abcdef")));
+
+    let empty = SCow::<SStr>::default() + "ghi";
+    assert!(empty.is_borrowed());
+    assert_eq!(&*(c + empty).into_owned(), "abcdefghi");
+
+    let mut o: SCow<SStr> = SString::from_string("x".to_owned()).into();
+    o.to_mut().as_string_mut().push('y');
+    assert_eq!(*o.as_ref(), *"xy");
+}
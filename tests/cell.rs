@@ -0,0 +1,53 @@
+use safe_types::std::cell::{SCell, SRef, SRefCell, SRefMut};
+use safe_types::{FromSafe, IntoSafe};
+use std::cell::{Cell, RefCell};
+
+#[test]
+fn cell() {
+    let cell = SCell::new(5);
+    cell.set(6);
+    assert_eq!(cell.replace(7), 6);
+    assert_eq!(cell.get(), 7);
+    assert_eq!(format!("{cell:?}"), "SCell { value: 7 }");
+
+    let cell = Cell::<i32>::from_safe(cell);
+    assert_eq!(cell.into_safe().into_inner(), 7);
+}
+
+#[test]
+fn refcell() {
+    let cell = SRefCell::new(vec![1, 2]);
+    {
+        let a = cell.borrow();
+        let b = SRef::clone(&a);
+        assert_eq!(*a, *b);
+        assert!(cell.try_borrow_mut().into_result().is_err());
+        assert_eq!(
+            cell.try_borrow_mut().into_result().unwrap_err().to_string(),
+            "already borrowed"
+        );
+        assert_eq!(format!("{cell:?}"), "SRefCell { value: [1, 2] }");
+    }
+    {
+        let mut m = cell.borrow_mut();
+        m.push(3);
+        assert!(cell.try_borrow().into_result().is_err());
+        assert_eq!(format!("{cell:?}"), "SRefCell { value: <borrowed> }");
+    }
+    assert_eq!(*SRef::map(cell.borrow(), |v| &v[2]), 3);
+    *SRefMut::map(cell.borrow_mut(), |v| &mut v[0]) = 10;
+    assert_eq!(cell.replace(vec![]), [10, 2, 3]);
+    assert!(cell.try_borrow_mut().into_result().is_ok());
+
+    let cell = RefCell::<u32>::from_safe(SRefCell::new(4));
+    let cell = cell.into_safe();
+    assert_eq!(cell.take(), 4);
+}
+
+#[test]
+#[should_panic(expected = "already mutably borrowed")]
+fn refcell_panics() {
+    let cell = SRefCell::new(0);
+    let _m = cell.borrow_mut();
+    let _r = cell.borrow();
+}
@@ -0,0 +1,84 @@
+use safe_types::std::io::SError;
+use safe_types::std::prelude::*;
+use safe_types::{STuple12, STuple3};
+use std::io;
+
+#[test]
+fn nested_round_trip() {
+    let ok: Result<Vec<String>, io::Error> = Ok(vec!["a".to_owned(), "b".to_owned()]);
+
+    let safe: SResult<SVec<SString>, SError> = ok.into_safe();
+    assert_eq!(safe.as_ref().unwrap()[1], *"b");
+
+    let back = Result::<Vec<String>, io::Error>::from_safe(safe);
+    assert_eq!(back.unwrap(), ["a", "b"]);
+
+    let err: Result<Vec<String>, io::Error> = Err(io::ErrorKind::NotFound.into());
+    let back = Result::<Vec<String>, io::Error>::from_safe(err.into_safe());
+    assert_eq!(back.unwrap_err().kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn borrowed_and_tuples() {
+    let owned = String::from("hello");
+    let safe = (owned.as_str(), [1u8, 2, 3]).into_safe();
+    assert_eq!(safe.0.as_str(), "hello");
+    assert_eq!(safe.1, [1, 2, 3]);
+
+    let (s, a) = <(&str, [u8; 3])>::from_safe(safe);
+    assert_eq!((s, a), ("hello", [1, 2, 3]));
+}
+
+#[test]
+fn larger_tuples() {
+    let safe = (1u8, "two".to_owned(), Some(3u32)).into_safe();
+    assert_eq!(
+        safe,
+        STuple3(1, SString::from("two".to_owned()), SOption::Some(3))
+    );
+    assert_ne!(
+        safe,
+        STuple3(1, SString::from("two".to_owned()), SOption::None)
+    );
+
+    let back = <(u8, String, Option<u32>)>::from_safe(safe);
+    assert_eq!(back, (1, "two".to_owned(), Some(3)));
+
+    type T12 = (
+        u8,
+        u16,
+        u32,
+        u64,
+        i8,
+        i16,
+        i32,
+        i64,
+        usize,
+        isize,
+        bool,
+        f64,
+    );
+    let t: T12 = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, true, 12.0);
+    let safe: STuple12<_, _, _, _, _, _, _, _, _, _, _, _> = t.into_safe();
+    assert_eq!(T12::from_safe(safe), t);
+}
+
+#[test]
+fn already_safe() {
+    let value = (
+        'x',
+        SString::from("name".to_owned()),
+        vec![SVec::from(vec![1u8, 2])],
+    );
+
+    let safe = value.clone().into_safe();
+    assert_eq!(safe.0, 'x');
+    assert_eq!(safe.1, value.1);
+    assert_eq!(safe.2[0], value.2[0]);
+
+    let back = <(char, SString, Vec<SVec<u8>>)>::from_safe(safe);
+    assert_eq!(back, value);
+
+    let tuple = STuple3(1u8, SOption::Some(2u32), SString::from("3".to_owned()));
+    assert_eq!(tuple.clone().into_safe(), tuple);
+}
@@ -0,0 +1,213 @@
+use safe_types::std::error::SDynError;
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug)]
+struct Outer(std::io::Error);
+
+impl Display for Outer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "loading config")
+    }
+}
+
+impl Error for Outer {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn source_chain() {
+    let original = Outer(std::io::Error::other("disk on fire"));
+    let error = SDynError::from_error(&original);
+
+    assert_eq!(error.type_name(), Some(std::any::type_name::<Outer>()));
+    assert_eq!(
+        error.chain().map(|e| e.to_string()).collect::<Vec<_>>(),
+        ["loading config", "disk on fire"]
+    );
+
+    let reconstructed = error.into_dyn_error();
+    assert_eq!(format!("{:?}", reconstructed), format!("{:?}", original));
+    let source = reconstructed.source().unwrap();
+    assert_eq!(source.to_string(), "disk on fire");
+    assert_eq!(format!("{:?}", source), format!("{:?}", original.0));
+    assert!(source.source().is_none());
+}
+
+#[test]
+fn type_names() {
+    let error = SDynError::from(Outer(std::io::Error::other("disk on fire")));
+    assert_eq!(error.type_name(), Some(std::any::type_name::<Outer>()));
+    // only reachable as `dyn Error`
+    assert_eq!(error.chain().nth(1).unwrap().type_name(), None);
+
+    // sources that were converted before keep their type name
+    let wrapped = SDynError::new(Wrapper(error.into()));
+    let names = wrapped.chain().map(|e| e.type_name()).collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            Some(std::any::type_name::<Wrapper>()),
+            Some(std::any::type_name::<Outer>()),
+            None
+        ]
+    );
+}
+
+#[derive(Debug)]
+struct Wrapper(Box<dyn Error + Send + Sync>);
+
+impl Display for Wrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "starting plugin")
+    }
+}
+
+impl Error for Wrapper {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct MyError(u32);
+
+impl Display for MyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "my error {}", self.0)
+    }
+}
+
+impl Error for MyError {}
+
+#[test]
+fn downcast() {
+    let mut error = SDynError::new(MyError(1));
+    assert_eq!(error.to_string(), "my error 1");
+    assert!(!error.is::<Outer>());
+
+    error.downcast_mut::<MyError>().unwrap().0 = 2;
+    assert_eq!(format!("{:?}", error), "MyError(2)");
+    assert_eq!(error.downcast::<MyError>().unwrap(), MyError(2));
+
+    // snapshots can't be downcast
+    assert!(SDynError::from_error(&MyError(3))
+        .downcast_ref::<MyError>()
+        .is_none());
+
+    // errors inside io::Error survive the round trip
+    let io = std::io::Error::other(MyError(4));
+    let io = safe_types::std::io::SError::from_error(io).into_error();
+    assert_eq!(
+        io.get_ref().unwrap().downcast_ref::<MyError>(),
+        Some(&MyError(4))
+    );
+}
+
+#[test]
+fn context() {
+    use safe_types::std::{
+        error::{Context, SAnyResult},
+        prelude::*,
+    };
+    use safe_types::SStr;
+
+    fn parse(s: &str) -> Result<u32, SDynError> {
+        Ok(s.parse::<u32>()?)
+    }
+    fn load(s: &str) -> Result<u32, SDynError> {
+        let n = parse(s).context("parsing")?;
+        Err::<u32, _>(MyError(n)).with_context(|| format!("loading {}", s))
+    }
+    extern "C" fn plugin_load(s: SStr) -> SAnyResult<u32> {
+        load(s.as_str()).into()
+    }
+
+    let error = plugin_load(SStr::from_str("x")).into_result().unwrap_err();
+    assert_eq!(
+        error.chain().map(|e| e.to_string()).collect::<Vec<_>>(),
+        ["parsing", "invalid digit found in string"]
+    );
+    assert!(error.is::<std::num::ParseIntError>());
+
+    let error = load("7").unwrap_err();
+    assert_eq!(error.to_string(), "loading 7");
+    assert_eq!(error.source().unwrap().to_string(), "my error 7");
+    assert_eq!(error.downcast::<MyError>().unwrap(), MyError(7));
+
+    let error = load("7").context("starting plugin").unwrap_err();
+    assert_eq!(
+        error.chain().map(|e| e.type_name()).collect::<Vec<_>>(),
+        [None, None, Some(std::any::type_name::<MyError>())]
+    );
+
+    // an SResult stays FFI-safe, so it can be returned from an extern "C" fn directly
+    extern "C" fn plugin_start(s: SStr) -> SAnyResult<u32> {
+        plugin_load(s).context("starting plugin")
+    }
+    let error = plugin_start(SStr::from_str("x")).into_result().unwrap_err();
+    assert_eq!(
+        error.chain().map(|e| e.to_string()).collect::<Vec<_>>(),
+        [
+            "starting plugin",
+            "parsing",
+            "invalid digit found in string"
+        ]
+    );
+
+    let none: SAnyResult<u32> = SOption::None.context("missing value");
+    assert_eq!(none.into_result().unwrap_err().to_string(), "missing value");
+}
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn anyhow_round_trip() {
+    use anyhow::Context;
+
+    let original = Err::<(), _>(MyError(5))
+        .context("parsing")
+        .context("loading config")
+        .unwrap_err();
+    let expected = format!("{:#}", original);
+
+    let error = SDynError::from_anyhow(original);
+    assert_eq!(error.chain().count(), 3);
+    assert_eq!(format!("{:#}", error.into_anyhow()), expected);
+
+    // a snapshot has to be wrapped, but prints the same
+    let snapshot = SDynError::from_error(&*anyhow::anyhow!(MyError(5)).context("parsing"));
+    let wrapped = snapshot.into_anyhow();
+    assert_eq!(format!("{:#}", wrapped), "parsing: my error 5");
+    assert_eq!(wrapped.chain().count(), 2);
+}
+
+#[cfg(feature = "eyre")]
+#[test]
+fn eyre_round_trip() {
+    use eyre::WrapErr;
+
+    let original = Err::<(), _>(MyError(5))
+        .wrap_err("parsing")
+        .wrap_err("loading config")
+        .unwrap_err();
+    let expected = format!("{:#}", original);
+
+    let error = SDynError::from_eyre(original);
+    assert_eq!(error.chain().count(), 3);
+    assert_eq!(format!("{:#}", error.into_eyre()), expected);
+
+    // a snapshot has to be wrapped, but prints the same
+    let snapshot = SDynError::from_error(&*eyre::eyre!(MyError(5)).wrap_err("parsing"));
+    let wrapped = snapshot.into_eyre();
+    assert_eq!(format!("{:#}", wrapped), "parsing: my error 5");
+    assert_eq!(wrapped.chain().count(), 2);
+}
+
+#[test]
+fn stays_small() {
+    assert_eq!(
+        std::mem::size_of::<SDynError>(),
+        std::mem::size_of::<usize>()
+    );
+}
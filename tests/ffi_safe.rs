@@ -3,8 +3,6 @@
 //! This won't compile if any of the tested types are found to not be
 //! FFI-safe
 
-use safe_types;
-
 #[test]
 fn ffi_safe() {
     extern "C" fn _test(
@@ -17,18 +15,67 @@ fn ffi_safe() {
         _: safe_types::SMutSlice<'static, u8>,
         // Primitives: Tuples
         _: safe_types::STuple2<u8, u8>,
+        _: safe_types::STuple3<u8, u16, u32>,
+        _: safe_types::STuple12<u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8>,
         // std
         _: safe_types::std::option::SOption<u8>,
         _: safe_types::std::result::SResult<u8, u8>,
         _: safe_types::std::boxed::SBox<u8>,
         _: safe_types::std::error::SDynError,
         _: safe_types::std::vec::SVec<u8>,
+        // std::cell
+        _: safe_types::std::cell::SCell<u8>,
+        _: safe_types::std::cell::SRefCell<u8>,
+        _: safe_types::std::cell::SRef<'static, u8>,
+        _: safe_types::std::cell::SRefMut<'static, u8>,
+        _: safe_types::std::cell::SBorrowError,
+        _: safe_types::std::cell::SBorrowMutError,
         // std::io
         _: safe_types::std::io::SError,
         _: safe_types::std::io::SErrorKind,
         _: safe_types::std::io::SResult<u8>,
+        // std::thread
+        _: safe_types::std::thread::SJoinHandle<u8>,
+        _: safe_types::std::thread::SThread,
+        _: safe_types::std::thread::SThreadId,
+        // std::panic
+        _: safe_types::std::panic::SPanicInfo,
+        _: safe_types::std::panic::SLocation,
+        // std::rc
+        _: safe_types::std::rc::SRc<u8>,
+        _: safe_types::std::rc::SWeakRc<u8>,
+        _: safe_types::std::rc::SRcOpaque,
+        _: safe_types::std::rc::SWeakRcOpaque,
         // std::sync
         _: safe_types::std::sync::SArcOpaque,
+        _: safe_types::std::sync::SWeakOpaque,
+        _: safe_types::std::sync::SArc<u8>,
+        _: safe_types::std::sync::SWeak<u8>,
+        _: safe_types::std::sync::SMutex<u8>,
+        _: safe_types::std::sync::SMutexGuard<'static, u8>,
+        _: safe_types::std::sync::SRwLock<u8>,
+        _: safe_types::std::sync::SRwLockReadGuard<'static, u8>,
+        _: safe_types::std::sync::SRwLockWriteGuard<'static, u8>,
+        _: safe_types::std::sync::SCondvar,
+        _: safe_types::std::sync::SWaitTimeoutResult,
+        _: safe_types::std::sync::SOnceLock<u8>,
+        _: safe_types::std::sync::SLazy<u8>,
+        _: safe_types::std::sync::SLockResult<u8>,
+        _: safe_types::std::sync::atomic::SAtomicBool,
+        _: safe_types::std::sync::atomic::SAtomicU32,
+        _: safe_types::std::sync::atomic::SAtomicI32,
+        _: safe_types::std::sync::atomic::SAtomicU64,
+        _: safe_types::std::sync::atomic::SAtomicI64,
+        _: safe_types::std::sync::atomic::SAtomicUsize,
+        _: safe_types::std::sync::atomic::SAtomicIsize,
+        _: safe_types::std::sync::atomic::SAtomicPtr<u8>,
+        _: safe_types::std::sync::mpsc::SSender<u8>,
+        _: safe_types::std::sync::mpsc::SReceiver<u8>,
+        _: safe_types::std::sync::mpsc::SSendError<u8>,
+        _: safe_types::std::sync::mpsc::SRecvError,
+        _: safe_types::std::sync::mpsc::STryRecvError,
+        _: safe_types::std::sync::mpsc::SRecvTimeoutError,
+        _: safe_types::std::sync::STryLockResult<u8>,
         // std::string
         _: safe_types::std::string::SString,
         // std::time
@@ -42,11 +89,17 @@ fn ffi_safe() {
         _: safe_types::std::net::SSocketAddrV6,
         _: safe_types::std::net::STcpStream,
         _: safe_types::std::net::SShutdown,
+        // std::future
+        _: safe_types::std::future::SFuture<u8>,
+        _: safe_types::std::future::SLocalFuture<u8>,
+        _: safe_types::std::future::SStream<u8>,
         // std::task
         _: safe_types::std::task::SContext,
         _: safe_types::std::task::SPoll<u8>,
+        _: safe_types::std::task::SWaker,
         // std::borrow
         _: safe_types::std::borrow::SCow<safe_types::SStr<'static>>,
+        _: safe_types::std::borrow::SCow<safe_types::SSlice<'static, u8>>,
     ) {
     }
 }
@@ -0,0 +1,107 @@
+use safe_types::std::future::{FutureExt, SFuture, SLocalFuture};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
+};
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Returns `Pending` once, waking the task from a stored clone of the waker
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        let waker = cx.waker().clone();
+        cx.waker().wake_by_ref();
+        waker.wake();
+        Poll::Pending
+    }
+}
+
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+
+    loop {
+        if let Poll::Ready(v) = future.as_mut().poll(&mut cx) {
+            return (v, counter.0.load(Ordering::SeqCst));
+        }
+    }
+}
+
+#[test]
+fn sfuture() {
+    let future: SFuture<u32> = async {
+        YieldOnce(false).await;
+        42
+    }
+    .into_sfuture();
+
+    let (output, wakes) = block_on(future);
+    assert_eq!(output, 42);
+    assert_eq!(wakes, 2);
+}
+
+#[test]
+fn slocal_future() {
+    let value = std::rc::Rc::new(5);
+    let future: SLocalFuture<i32> = async move {
+        YieldOnce(false).await;
+        *value
+    }
+    .into_slocal_future();
+
+    assert_eq!(block_on(future).0, 5);
+}
+
+#[test]
+fn nested() {
+    let inner = SFuture::new(async { String::from("inner") });
+    let outer = async move { inner.await + " outer" }.into_sfuture();
+
+    assert_eq!(block_on(outer).0, "inner outer");
+}
+
+#[test]
+fn poll_after_ready() {
+    // the caller panics before the completed `async` block is resumed across the boundary
+    let mut future = SFuture::new(async { 1 });
+    let waker = Waker::noop();
+    let mut cx = Context::from_waker(waker);
+
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(1));
+    let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = Pin::new(&mut future).poll(&mut cx);
+    }))
+    .unwrap_err();
+    assert_eq!(
+        panic.downcast_ref::<&str>(),
+        Some(&"future polled after completion")
+    );
+
+    let mut future = SLocalFuture::new(async { 2 });
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(2));
+    assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = Pin::new(&mut future).poll(&mut cx);
+    }))
+    .is_err());
+}
@@ -0,0 +1,151 @@
+use safe_types::std::io::{SError, SErrorKind};
+use std::io::ErrorKind;
+
+#[test]
+fn error_api() {
+    let mut error = SError::new(SErrorKind::InvalidData, "bad header");
+    assert_eq!(error, SErrorKind::InvalidData);
+    assert_eq!(error, ErrorKind::InvalidData);
+    assert_eq!(error.raw_os_error(), None);
+    assert_eq!(error.get_ref().unwrap().to_string(), "bad header");
+    assert!(error.get_mut().is_some());
+    assert_eq!(error.into_inner().unwrap().to_string(), "bad header");
+
+    let error = SError::other(std::fmt::Error);
+    assert_eq!(error.kind(), SErrorKind::Other);
+    assert!(error.get_ref().unwrap().is::<std::fmt::Error>());
+
+    let error = SError::from(SErrorKind::NotFound);
+    assert!(error.get_ref().is_none());
+}
+
+#[cfg(unix)]
+#[test]
+fn os_error() {
+    let error = SError::from_raw_os_error(libc::ENOENT);
+    assert_eq!(error.raw_os_error(), Some(libc::ENOENT));
+    assert_eq!(error.kind(), SErrorKind::NotFound);
+    assert!(error.into_inner().is_none());
+}
+
+#[cfg(feature = "errno")]
+#[test]
+fn errno() {
+    let error = SError::new(SErrorKind::InvalidData, "bad header");
+    assert_eq!(error.to_errno(), libc::EINVAL);
+    let error = SError::other(std::fmt::Error);
+    assert_eq!(error.to_errno(), libc::EIO);
+    let error = SError::from(SErrorKind::NotFound);
+    assert_eq!(error.to_errno(), libc::ENOENT);
+
+    #[cfg(unix)]
+    {
+        let error = SError::from_raw_os_error(libc::ECHILD);
+        assert_eq!(error.to_errno(), libc::ECHILD);
+    }
+}
+
+#[test]
+fn error_kind_codes() {
+    // these must never change
+    let kinds = [
+        (SErrorKind::NotFound, ErrorKind::NotFound),
+        (SErrorKind::PermissionDenied, ErrorKind::PermissionDenied),
+        (SErrorKind::ConnectionRefused, ErrorKind::ConnectionRefused),
+        (SErrorKind::ConnectionReset, ErrorKind::ConnectionReset),
+        (SErrorKind::ConnectionAborted, ErrorKind::ConnectionAborted),
+        (SErrorKind::NotConnected, ErrorKind::NotConnected),
+        (SErrorKind::AddrInUse, ErrorKind::AddrInUse),
+        (SErrorKind::AddrNotAvailable, ErrorKind::AddrNotAvailable),
+        (SErrorKind::BrokenPipe, ErrorKind::BrokenPipe),
+        (SErrorKind::AlreadyExists, ErrorKind::AlreadyExists),
+        (SErrorKind::WouldBlock, ErrorKind::WouldBlock),
+        (SErrorKind::InvalidInput, ErrorKind::InvalidInput),
+        (SErrorKind::InvalidData, ErrorKind::InvalidData),
+        (SErrorKind::TimedOut, ErrorKind::TimedOut),
+        (SErrorKind::WriteZero, ErrorKind::WriteZero),
+        (SErrorKind::Interrupted, ErrorKind::Interrupted),
+        (SErrorKind::Unsupported, ErrorKind::Unsupported),
+        (SErrorKind::UnexpectedEof, ErrorKind::UnexpectedEof),
+        (SErrorKind::OutOfMemory, ErrorKind::OutOfMemory),
+        (SErrorKind::Other, ErrorKind::Other),
+        (SErrorKind::HostUnreachable, ErrorKind::HostUnreachable),
+        (
+            SErrorKind::NetworkUnreachable,
+            ErrorKind::NetworkUnreachable,
+        ),
+        (SErrorKind::NetworkDown, ErrorKind::NetworkDown),
+        (SErrorKind::NotADirectory, ErrorKind::NotADirectory),
+        (SErrorKind::IsADirectory, ErrorKind::IsADirectory),
+        (SErrorKind::DirectoryNotEmpty, ErrorKind::DirectoryNotEmpty),
+        (
+            SErrorKind::ReadOnlyFilesystem,
+            ErrorKind::ReadOnlyFilesystem,
+        ),
+        (
+            SErrorKind::StaleNetworkFileHandle,
+            ErrorKind::StaleNetworkFileHandle,
+        ),
+        (SErrorKind::StorageFull, ErrorKind::StorageFull),
+        (SErrorKind::NotSeekable, ErrorKind::NotSeekable),
+        (SErrorKind::QuotaExceeded, ErrorKind::QuotaExceeded),
+        (SErrorKind::FileTooLarge, ErrorKind::FileTooLarge),
+        (SErrorKind::ResourceBusy, ErrorKind::ResourceBusy),
+        (
+            SErrorKind::ExecutableFileBusy,
+            ErrorKind::ExecutableFileBusy,
+        ),
+        (SErrorKind::Deadlock, ErrorKind::Deadlock),
+        (SErrorKind::CrossesDevices, ErrorKind::CrossesDevices),
+        (SErrorKind::TooManyLinks, ErrorKind::TooManyLinks),
+        (SErrorKind::InvalidFilename, ErrorKind::InvalidFilename),
+        (
+            SErrorKind::ArgumentListTooLong,
+            ErrorKind::ArgumentListTooLong,
+        ),
+    ];
+
+    for (code, (kind, std_kind)) in kinds.into_iter().enumerate() {
+        assert_eq!(kind.as_code(), code as u32);
+        assert_eq!(SErrorKind::from_code(code as u32), kind);
+        assert_eq!(SErrorKind::from_errorkind(&std_kind), kind);
+        assert_eq!(kind.as_errorkind(), std_kind);
+    }
+
+    // codes from newer versions survive
+    let unknown = SErrorKind::from_code(1000);
+    assert!(!unknown.is_known());
+    assert_eq!(format!("{:?}", unknown), "Unknown(1000)");
+    assert_eq!(unknown.as_errorkind(), ErrorKind::Other);
+    let error = SError::from(unknown);
+    assert_eq!(error.kind(), unknown);
+    assert_eq!(error.kind().as_code(), 1000);
+
+    // there is only one value per code, and known ones can be matched on
+    assert_eq!(SErrorKind::from_code(0), SErrorKind::NotFound);
+    assert!(matches!(SErrorKind::from_code(12), SErrorKind::InvalidData));
+    assert_eq!(format!("{:?}", SErrorKind::NotFound), "NotFound");
+    assert_eq!(std::mem::size_of::<SErrorKind>(), 4);
+}
+
+#[test]
+fn formatting() {
+    use std::io::{Error, Read};
+
+    let mut buf = [0; 4];
+    let simple_message = [0u8; 2].as_slice().read_exact(&mut buf).unwrap_err();
+
+    let errors = [
+        Error::from_raw_os_error(2),
+        Error::from(ErrorKind::NotFound),
+        simple_message,
+        Error::new(ErrorKind::InvalidData, "bad header"),
+        Error::other(std::fmt::Error),
+    ];
+
+    for error in errors {
+        let expected = (format!("{}", error), format!("{:?}", error));
+        let error = SError::from_error(error);
+        assert_eq!((format!("{}", error), format!("{:?}", error)), expected);
+    }
+}
@@ -0,0 +1,47 @@
+use safe_types::std::panic::ffi_catch_unwind;
+
+#[test]
+fn catch() {
+    assert_eq!(ffi_catch_unwind(|| 5).into_result().unwrap(), 5);
+
+    let line = line!() + 1;
+    let info = ffi_catch_unwind(|| panic!("plugin {} failed", 1))
+        .into_result()
+        .unwrap_err();
+    assert_eq!(info.message(), "plugin 1 failed");
+    let location = info.location().unwrap();
+    assert_eq!(location.file(), file!());
+    assert_eq!(location.line(), line);
+    assert_eq!(
+        info.to_string(),
+        format!("panicked at {}:\nplugin 1 failed", location)
+    );
+
+    let info = ffi_catch_unwind(|| std::panic::panic_any(5u8))
+        .into_result()
+        .unwrap_err();
+    assert_eq!(info.message(), "Box<dyn Any>");
+}
+
+#[test]
+fn resume() {
+    let info = ffi_catch_unwind(|| panic!("static message"))
+        .into_result()
+        .unwrap_err();
+
+    let payload = std::panic::catch_unwind(|| info.resume_unwind()).unwrap_err();
+    assert_eq!(payload.downcast_ref::<String>().unwrap(), "static message");
+}
+
+#[test]
+fn resume_without_location() {
+    // installs the hook, so the next panic records its location
+    let _ = ffi_catch_unwind(|| ());
+    let _ = std::panic::catch_unwind(|| panic!("caught elsewhere"));
+
+    let info = ffi_catch_unwind(|| std::panic::resume_unwind(Box::new("resumed".to_owned())))
+        .into_result()
+        .unwrap_err();
+    assert_eq!(info.message(), "resumed");
+    assert!(info.location().is_none());
+}
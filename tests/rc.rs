@@ -0,0 +1,86 @@
+use safe_types::std::rc::{SRc, SRcOpaque, SWeakRc, SWeakRcOpaque};
+use safe_types::{FromSafe, IntoSafe};
+use std::rc::Rc;
+
+#[test]
+fn rc() {
+    let rc = Rc::new(vec![1, 2, 3]);
+    let mut a = SRc::from_rc(rc.clone());
+    assert_eq!(*a, [1, 2, 3]);
+    assert_eq!(SRc::strong_count(&a), 2);
+    assert!(SRc::get_mut(&mut a).is_none());
+
+    let w = SRc::downgrade(&a);
+    assert_eq!(SRc::weak_count(&a), 1);
+    assert!(SRc::ptr_eq(&w.upgrade().unwrap(), &a));
+
+    SRc::make_mut(&mut a).push(4);
+    assert_eq!(*a, [1, 2, 3, 4]);
+    assert_eq!(*rc, [1, 2, 3]);
+    assert_eq!(w.upgrade().unwrap().len(), 3);
+
+    let back = Rc::from_safe(rc.clone().into_safe());
+    assert!(Rc::ptr_eq(&rc, &back));
+
+    drop((rc, back));
+    assert!(w.upgrade().is_none());
+    assert!(SWeakRc::<u8>::new().upgrade().is_none());
+}
+
+#[test]
+fn opaque() {
+    struct NotFfiSafe(String);
+
+    let rc = Rc::new(NotFfiSafe("state".to_owned()));
+    let opaque = SRcOpaque::new(rc.clone());
+    let clone = opaque.clone();
+    assert_eq!(SRcOpaque::strong_count(&opaque), 3);
+    assert!(SRcOpaque::ptr_eq(&opaque, &clone));
+    assert_eq!(
+        format!("{:?}", opaque),
+        format!("SRcOpaque<{}>", std::any::type_name::<NotFfiSafe>())
+    );
+
+    let weak = SRcOpaque::downgrade(&opaque);
+    assert_eq!(weak.weak_count(), 1);
+
+    let opaque = opaque.try_into_rc::<String>().unwrap_err();
+    let back = opaque.try_into_rc::<NotFfiSafe>().unwrap();
+    assert!(Rc::ptr_eq(&rc, &back));
+    assert_eq!(back.0, "state");
+
+    drop((rc, back, clone));
+    assert!(weak.upgrade().is_none());
+
+    let typed = SRc::new(5u32);
+    let opaque = SRcOpaque::from(typed.clone());
+    assert_eq!(SRc::strong_count(&typed), 2);
+    assert_eq!(*opaque.try_into_rc::<u32>().unwrap(), 5);
+}
+
+/// Fails to compile if `$ty` implements `$trait`, since the
+/// blanket impls below then make the call ambiguous
+macro_rules! assert_not_impl {
+    ($ty:ty: $trait:path) => {{
+        trait AmbiguousIfImpl<A> {
+            fn some_item() {}
+        }
+        impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+        struct Invalid;
+        impl<T: ?Sized + $trait> AmbiguousIfImpl<Invalid> for T {}
+
+        <$ty as AmbiguousIfImpl<_>>::some_item()
+    }};
+}
+
+#[test]
+fn not_thread_safe() {
+    assert_not_impl!(SRc<u8>: Send);
+    assert_not_impl!(SRc<u8>: Sync);
+    assert_not_impl!(SWeakRc<u8>: Send);
+    assert_not_impl!(SWeakRc<u8>: Sync);
+    assert_not_impl!(SRcOpaque: Send);
+    assert_not_impl!(SRcOpaque: Sync);
+    assert_not_impl!(SWeakRcOpaque: Send);
+    assert_not_impl!(SWeakRcOpaque: Sync);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "safe_extern")]
+
+use safe_types::safe_extern;
+use std::io;
+
+#[safe_extern]
+fn safe_extern_handle(name: &str, data: Vec<u8>) -> Result<String, io::Error> {
+    if data.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "no data"));
+    }
+
+    Ok(format!("{}: {}", name, data.len()))
+}
+
+#[safe_extern]
+fn safe_extern_unit((a, b): (u32, u32)) {
+    assert_eq!(a, b);
+}
+
+mod caller {
+    use safe_types::safe_extern;
+    use std::io;
+
+    #[safe_extern]
+    extern "C" {
+        pub fn safe_extern_handle(name: &str, data: Vec<u8>) -> Result<String, io::Error>;
+        #[link_name = "safe_extern_unit"]
+        pub fn unit(pair: (u32, u32));
+    }
+}
+
+#[test]
+fn round_trip() {
+    let result = unsafe { caller::safe_extern_handle("plugin", vec![1, 2, 3]) };
+    assert_eq!(result.unwrap(), "plugin: 3");
+
+    let error = unsafe { caller::safe_extern_handle("plugin", Vec::new()) }.unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(error.to_string(), "no data");
+
+    unsafe { caller::unit((1, 1)) };
+}
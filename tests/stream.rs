@@ -0,0 +1,114 @@
+#[cfg(feature = "futures")]
+use futures_core::Stream;
+use safe_types::std::future::SStream;
+#[cfg(feature = "futures")]
+use safe_types::std::future::StreamExt;
+#[cfg(feature = "futures")]
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+/// Yields the numbers below `end`, returning `Pending` before each one
+#[cfg(feature = "futures")]
+struct Counter {
+    next: u32,
+    end: u32,
+    ready: bool,
+}
+
+#[cfg(feature = "futures")]
+impl Stream for Counter {
+    type Item = u32;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        if self.next == self.end {
+            return Poll::Ready(None);
+        }
+        self.ready = !self.ready;
+        if !self.ready {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        self.next += 1;
+        Poll::Ready(Some(self.next - 1))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.next) as usize;
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "futures")]
+fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut items = Vec::new();
+
+    loop {
+        match Pin::new(&mut stream).poll_next(&mut cx) {
+            Poll::Ready(Some(item)) => items.push(item),
+            Poll::Ready(None) => return items,
+            Poll::Pending => {}
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "futures")]
+fn sstream() {
+    let stream = Counter {
+        next: 0,
+        end: 3,
+        ready: true,
+    }
+    .into_sstream();
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+    assert_eq!(collect(stream), [0, 1, 2]);
+
+    // an SStream is itself a Stream, so it can be wrapped again
+    let nested = SStream::new(SStream::new(Counter {
+        next: 5,
+        end: 7,
+        ready: true,
+    }));
+    assert_eq!(nested.size_hint(), (2, Some(2)));
+    assert_eq!(collect(nested), [5, 6]);
+}
+
+#[test]
+fn from_poll_fn() {
+    let mut next = 0;
+    let mut stream = SStream::from_poll_fn(move |cx| {
+        next += 1;
+        match next {
+            1 => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            2 | 3 => Poll::Ready(Some(next)),
+            _ => Poll::Ready(None),
+        }
+    });
+    assert_eq!(stream.size_hint(), (0, None));
+
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(stream.poll_next(&mut cx), Poll::Pending);
+    assert_eq!(stream.poll_next(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next(&mut cx), Poll::Ready(Some(3)));
+    assert_eq!(stream.poll_next(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn poll_after_end() {
+    // the closure would panic inside the vtable, which aborts
+    let mut ended = false;
+    let mut stream = SStream::from_poll_fn(move |_| {
+        assert!(!ended, "polled after the end");
+        ended = true;
+        Poll::<Option<u32>>::Ready(None)
+    });
+
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(stream.poll_next(&mut cx), Poll::Ready(None));
+    assert_eq!(stream.poll_next(&mut cx), Poll::Ready(None));
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+}
@@ -0,0 +1,413 @@
+use safe_types::std::sync::{SArc, SWeak};
+use safe_types::{FromSafe, IntoSafe};
+use std::sync::Arc;
+
+#[test]
+fn arc() {
+    let arc = Arc::new(vec![1, 2, 3]);
+    let mut a = SArc::from_arc(arc.clone());
+    assert_eq!(*a, [1, 2, 3]);
+    assert_eq!(SArc::strong_count(&a), 2);
+    assert!(SArc::get_mut(&mut a).is_none());
+
+    let b = a.clone();
+    assert!(SArc::ptr_eq(&a, &b));
+    assert_eq!(SArc::strong_count(&a), 3);
+    drop(b);
+
+    SArc::make_mut(&mut a).push(4);
+    assert_eq!(*a, [1, 2, 3, 4]);
+    assert_eq!(*arc, [1, 2, 3]);
+    assert_eq!(Arc::strong_count(&arc), 1);
+
+    let a = SArc::try_unwrap(a).unwrap();
+    assert_eq!(a, [1, 2, 3, 4]);
+
+    let s = arc.clone().into_safe();
+    let back = Arc::from_safe(s);
+    assert!(Arc::ptr_eq(&arc, &back));
+}
+
+#[test]
+fn weak() {
+    let a = SArc::new(String::from("config"));
+    let w = SArc::downgrade(&a);
+    assert_eq!(SArc::weak_count(&a), 1);
+    assert_eq!(w.strong_count(), 1);
+
+    let w2 = w.clone();
+    assert_eq!(w.weak_count(), 2);
+    assert_eq!(*w2.upgrade().unwrap(), "config");
+
+    // weak references don't prevent unwrapping
+    assert_eq!(SArc::try_unwrap(a).unwrap(), "config");
+    assert!(w.upgrade().is_none());
+    assert_eq!(w.strong_count(), 0);
+
+    assert!(SWeak::<u8>::new().upgrade().is_none());
+}
+
+#[test]
+fn opaque() {
+    use safe_types::std::sync::SArcOpaque;
+
+    struct NotFfiSafe(String);
+
+    let arc = Arc::new(NotFfiSafe("state".to_owned()));
+    let opaque = SArcOpaque::new(arc.clone());
+    let clone = opaque.clone();
+    assert_eq!(SArcOpaque::strong_count(&opaque), 3);
+    assert!(SArcOpaque::ptr_eq(&opaque, &clone));
+    assert_eq!(
+        format!("{:?}", opaque),
+        format!("SArcOpaque<{}>", std::any::type_name::<NotFfiSafe>())
+    );
+
+    let weak = SArcOpaque::downgrade(&opaque);
+    assert_eq!(weak.weak_count(), 1);
+
+    let opaque = opaque.try_into_arc::<String>().unwrap_err();
+    let back = opaque.try_into_arc::<NotFfiSafe>().unwrap();
+    assert!(Arc::ptr_eq(&arc, &back));
+    assert_eq!(back.0, "state");
+
+    drop((arc, back, clone));
+    assert!(weak.upgrade().is_none());
+
+    let typed = SArc::new(5u32);
+    let opaque = SArcOpaque::from(typed.clone());
+    assert_eq!(SArc::strong_count(&typed), 2);
+    assert_eq!(*opaque.try_into_arc::<u32>().unwrap(), 5);
+}
+
+#[test]
+fn opaque_from_foreign_arc() {
+    use safe_types::std::sync::SArcOpaque;
+
+    let foreign = SArc::__new_foreign(5u32);
+    let foreign = foreign.try_into_arc().unwrap_err();
+
+    let opaque = SArcOpaque::from(foreign.clone());
+    assert_eq!(SArcOpaque::strong_count(&opaque), 2);
+    let opaque = opaque.try_into_arc::<u32>().unwrap_err();
+    drop(opaque);
+    assert_eq!(SArc::strong_count(&foreign), 1);
+}
+
+#[test]
+fn mutex() {
+    use safe_types::std::sync::{SMutex, STryLockError};
+
+    let mutex = Arc::new(SMutex::new(0));
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let mutex = mutex.clone();
+            std::thread::spawn(move || {
+                for _ in 0..100 {
+                    *mutex.lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(*mutex.lock().unwrap(), 400);
+
+    let guard = mutex.lock().unwrap();
+    assert!(matches!(
+        mutex.try_lock().into_result(),
+        Err(STryLockError::WouldBlock)
+    ));
+    drop(guard);
+    assert!(mutex.try_lock().into_result().is_ok());
+
+    let clone = mutex.clone();
+    std::thread::spawn(move || {
+        let _guard = clone.lock().unwrap();
+        panic!("poison");
+    })
+    .join()
+    .unwrap_err();
+
+    assert!(mutex.is_poisoned());
+    let error = mutex.lock().into_result().unwrap_err();
+    assert_eq!(**error.get_ref(), 400);
+    drop(error);
+    mutex.clear_poison();
+
+    let mutex = Arc::into_inner(mutex).unwrap();
+    assert_eq!(mutex.into_inner().into_result().unwrap(), 400);
+}
+
+#[test]
+fn mutex_in_drop_while_unwinding() {
+    use safe_types::std::sync::SMutex;
+    use std::panic::AssertUnwindSafe;
+
+    struct LockOnDrop<'a>(&'a SMutex<u32>);
+
+    impl Drop for LockOnDrop<'_> {
+        fn drop(&mut self) {
+            *self.0.lock().unwrap() += 1;
+        }
+    }
+
+    let mutex = SMutex::new(0);
+    std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let _lock_on_drop = LockOnDrop(&mutex);
+        panic!("unwinding");
+    }))
+    .unwrap_err();
+
+    // the panic started before the lock was taken
+    assert!(!mutex.is_poisoned());
+    assert_eq!(*mutex.lock().unwrap(), 1);
+}
+
+#[test]
+fn rwlock() {
+    use safe_types::std::sync::{SRwLock, STryLockError};
+    use std::sync::RwLock;
+
+    let lock = SRwLock::new(vec![1]);
+    {
+        let a = lock.read().unwrap();
+        let b = lock.try_read().unwrap();
+        assert_eq!(*a, *b);
+        assert!(matches!(
+            lock.try_write().into_result(),
+            Err(STryLockError::WouldBlock)
+        ));
+    }
+    lock.write().unwrap().push(2);
+    assert_eq!(*lock.read().unwrap(), [1, 2]);
+
+    let lock = Arc::new(lock);
+    let clone = lock.clone();
+    std::thread::spawn(move || {
+        let _guard = clone.write().unwrap();
+        panic!("poison");
+    })
+    .join()
+    .unwrap_err();
+    assert!(lock.is_poisoned());
+    assert!(lock.read().into_result().is_err());
+
+    let std_lock = RwLock::new(5);
+    let lock = SRwLock::from_rwlock(std_lock);
+    assert!(!lock.is_poisoned());
+    assert_eq!(lock.into_inner().into_result().unwrap(), 5);
+}
+
+#[test]
+fn rwlock_in_drop_while_unwinding() {
+    use safe_types::std::sync::SRwLock;
+    use std::panic::AssertUnwindSafe;
+
+    struct WriteOnDrop<'a>(&'a SRwLock<u32>);
+
+    impl Drop for WriteOnDrop<'_> {
+        fn drop(&mut self) {
+            *self.0.write().unwrap() += 1;
+        }
+    }
+
+    let lock = SRwLock::new(0);
+    std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let _write_on_drop = WriteOnDrop(&lock);
+        panic!("unwinding");
+    }))
+    .unwrap_err();
+
+    // the panic started before the lock was taken
+    assert!(!lock.is_poisoned());
+    assert_eq!(*lock.read().unwrap(), 1);
+}
+
+#[test]
+fn condvar() {
+    use safe_types::std::sync::{SCondvar, SMutex};
+    use safe_types::std::time::SDuration;
+    use std::time::Duration;
+
+    let pair = Arc::new((SMutex::new(false), SCondvar::new()));
+    let clone = pair.clone();
+    let thread = std::thread::spawn(move || {
+        let (lock, cvar) = &*clone;
+        *lock.lock().unwrap() = true;
+        cvar.notify_one();
+    });
+
+    let (lock, cvar) = &*pair;
+    let started = cvar.wait_while(lock.lock().unwrap(), |started| !*started);
+    assert!(*started.unwrap());
+    thread.join().unwrap();
+
+    let guard = lock.lock().unwrap();
+    let (guard, result) = cvar
+        .wait_timeout(guard, SDuration::from_duration(Duration::from_millis(10)))
+        .unwrap();
+    assert!(result.timed_out());
+    assert!(*guard);
+}
+
+#[test]
+fn condvar_with_foreign_mutex() {
+    use safe_types::std::sync::{SCondvar, SMutex};
+
+    let pair = Arc::new((SMutex::__new_foreign(0), SCondvar::new()));
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let pair = pair.clone();
+            std::thread::spawn(move || {
+                let (lock, cvar) = &*pair;
+                *lock.lock().unwrap() += 1;
+                cvar.notify_all();
+            })
+        })
+        .collect();
+
+    let (lock, cvar) = &*pair;
+    let count = cvar.wait_while(lock.lock().unwrap(), |count| *count < 4);
+    assert_eq!(*count.unwrap(), 4);
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
+
+#[test]
+fn once_lock() {
+    use safe_types::std::sync::SOnceLock;
+
+    let cell = Arc::new(SOnceLock::new());
+    assert!(cell.get().is_none());
+
+    let threads: Vec<_> = (0..4)
+        .map(|i| {
+            let cell = cell.clone();
+            std::thread::spawn(move || *cell.get_or_init(|| i))
+        })
+        .collect();
+    let values: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+    assert!(values.iter().all(|v| *v == values[0]));
+    assert_eq!(cell.set(10), Err(10));
+
+    let cell = SOnceLock::new();
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        cell.get_or_init(|| panic!("init"))
+    }))
+    .unwrap_err();
+    assert!(cell.get().is_none());
+    assert_eq!(cell.set(String::from("late")), Ok(()));
+    assert_eq!(cell.into_inner().unwrap(), "late");
+}
+
+#[test]
+fn lazy() {
+    use safe_types::std::sync::SLazy;
+
+    use safe_types::std::vec::SVec;
+
+    extern "C" fn init() -> SVec<u32> {
+        SVec::from_vec(vec![1, 2, 3])
+    }
+
+    let lazy: SLazy<SVec<u32>> = SLazy::new(init);
+    assert_eq!(format!("{lazy:?}"), "SLazy(<uninit>)");
+    assert_eq!(lazy.len(), 3);
+    assert_eq!(format!("{lazy:?}"), "SLazy([1, 2, 3])");
+    assert_eq!(SLazy::into_inner(lazy).unwrap().into_vec(), [1, 2, 3]);
+
+    let count = std::sync::atomic::AtomicU32::new(0);
+    let lazy = SLazy::new(|| count.fetch_add(1, std::sync::atomic::Ordering::Relaxed));
+    assert_eq!(*lazy, 0);
+    assert_eq!(*lazy, 0);
+}
+
+#[test]
+fn mpsc() {
+    use safe_types::std::sync::mpsc::{self, SRecvTimeoutError, STryRecvError};
+    use safe_types::std::time::SDuration;
+    use std::time::Duration;
+
+    let (tx, rx) = mpsc::channel();
+    assert_eq!(rx.try_recv().into_result(), Err(STryRecvError::Empty));
+
+    let threads: Vec<_> = (0..4)
+        .map(|i| {
+            let tx = tx.clone();
+            std::thread::spawn(move || tx.send(String::from("msg") + &i.to_string()).unwrap())
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    let mut received: Vec<_> = rx.try_iter().collect();
+    received.sort();
+    assert_eq!(received, ["msg0", "msg1", "msg2", "msg3"]);
+
+    let timeout = SDuration::from_duration(Duration::from_millis(10));
+    assert_eq!(
+        rx.recv_timeout(timeout).into_result(),
+        Err(SRecvTimeoutError::Timeout)
+    );
+
+    drop(tx);
+    assert!(rx.recv().into_result().is_err());
+    assert_eq!(
+        rx.try_recv().into_result(),
+        Err(STryRecvError::Disconnected)
+    );
+
+    let (tx, rx) = mpsc::channel();
+    drop(rx);
+    let error = tx.send(vec![1, 2, 3]).into_result().unwrap_err();
+    assert_eq!(error.0, [1, 2, 3]);
+    assert_eq!(error.to_string(), "sending on a closed channel");
+}
+
+#[test]
+fn atomic() {
+    use safe_types::std::sync::atomic::{Ordering, SAtomicBool, SAtomicPtr, SAtomicU64};
+    use std::mem::{align_of, size_of};
+    use std::sync::atomic::AtomicU64;
+
+    assert_eq!(size_of::<SAtomicU64>(), size_of::<u64>());
+    assert_eq!(align_of::<SAtomicU64>(), size_of::<u64>());
+    assert_eq!(size_of::<SAtomicBool>(), 1);
+
+    let counter = Arc::new(SAtomicU64::new(0));
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let counter = counter.clone();
+            std::thread::spawn(move || {
+                for _ in 0..100 {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(counter.load(Ordering::SeqCst), 400);
+    assert_eq!(
+        counter.compare_exchange(400, 1, Ordering::SeqCst, Ordering::SeqCst),
+        Ok(400)
+    );
+
+    let counter = Arc::into_inner(counter).unwrap();
+    let std_counter = AtomicU64::from_safe(counter);
+    assert_eq!(std_counter.into_safe().into_inner(), 1);
+
+    let flag = SAtomicBool::default();
+    flag.store(true, Ordering::Release);
+    assert!(flag.swap(false, Ordering::AcqRel));
+
+    let mut value = 5;
+    let ptr = SAtomicPtr::new(std::ptr::null_mut());
+    ptr.store(&mut value, Ordering::Relaxed);
+    assert_eq!(unsafe { *ptr.load(Ordering::Relaxed) }, 5);
+}
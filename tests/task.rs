@@ -0,0 +1,79 @@
+use safe_types::std::task::{SContext, SWaker};
+use safe_types::{FromSafe, IntoSafe};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Wake, Waker},
+};
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn counting_waker() -> (Arc<CountingWaker>, Waker) {
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+
+    (counter, waker)
+}
+
+#[test]
+fn swaker() {
+    let (counter, waker) = counting_waker();
+
+    let swaker = SWaker::from_waker(waker.clone());
+    swaker.wake_by_ref();
+    let clone = swaker.clone();
+    assert!(swaker.will_wake(&clone));
+    clone.wake();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+
+    // stored and woken later from another thread
+    std::thread::spawn(move || swaker.wake()).join().unwrap();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 3);
+
+    let (_, other) = counting_waker();
+    assert!(!SWaker::from_waker(other).will_wake(&waker.clone().into_safe()));
+
+    let back = Waker::from_safe(waker.clone().into_safe());
+    assert!(back.will_wake(&waker));
+    back.wake();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn round_trip() {
+    let (counter, waker) = counting_waker();
+
+    // a local SWaker gives back the original waker
+    let back = SWaker::from_waker(waker.clone()).into_waker();
+    assert!(back.will_wake(&waker));
+
+    // a waker wrapping an SWaker is unwrapped instead of wrapped again
+    let cx = Context::from_waker(&waker);
+    let wrapping = SContext::from_context(&cx, |mut scx| scx.with_context(|cx| cx.waker().clone()));
+    let swaker = SWaker::from_waker(wrapping.clone());
+    assert!(swaker.will_wake(&SWaker::from_waker(wrapping)));
+
+    swaker.wake();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn context_waker() {
+    let (counter, waker) = counting_waker();
+    let cx = Context::from_waker(&waker);
+
+    let stored = SContext::from_context(&cx, |scx| {
+        scx.waker().wake_by_ref();
+        scx.waker().clone()
+    });
+    stored.wake();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,71 @@
+use safe_types::std::thread::{self, SJoinHandle, SThreadId};
+
+#[test]
+fn spawn_and_join() {
+    let handle = thread::spawn(|| 2 + 2);
+    assert_eq!(handle.join().unwrap(), 4);
+
+    let handle = thread::spawn(|| -> u32 { panic!("worker failed") });
+    let info = handle.join().unwrap_err();
+    assert_eq!(info.message(), "worker failed");
+    assert_eq!(info.location().unwrap().file(), file!());
+
+    let handle = SJoinHandle::from_join_handle(
+        std::thread::Builder::new()
+            .name("worker".to_owned())
+            .spawn(|| String::from("done"))
+            .unwrap(),
+    );
+    assert_eq!(
+        handle.thread().name().into_option().unwrap().as_str(),
+        "worker"
+    );
+    assert_eq!(handle.join().unwrap(), "done");
+}
+
+#[test]
+fn park_and_unpark() {
+    let handle = thread::spawn(|| {
+        thread::park();
+        thread::current().id()
+    });
+
+    let id = handle.thread().id();
+    assert_ne!(id, thread::current().id());
+    handle.thread().unpark();
+    while !handle.is_finished() {
+        std::thread::yield_now();
+    }
+    assert_eq!(handle.join().unwrap(), id);
+    assert_eq!(
+        thread::current().id(),
+        SThreadId::from_thread_id(std::thread::current().id())
+    );
+}
+
+#[test]
+fn thread_ids() {
+    let current = thread::current().id();
+    assert_eq!(current, thread::current().id());
+    assert_eq!(current.as_u64(), thread::current().id().as_u64());
+
+    // a thread's number is only looked up while it's running
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let other = std::thread::spawn(move || {
+        let id = thread::current().id();
+        receiver.recv().unwrap();
+        id
+    });
+    let other_id = SThreadId::from_thread_id(other.thread().id());
+    sender.send(()).unwrap();
+    assert_eq!(other.join().unwrap(), other_id);
+    assert_ne!(other_id, current);
+    assert_ne!(other_id.as_u64(), current.as_u64());
+
+    // handles keep the number after the thread exits
+    let handle = thread::spawn(|| thread::current().id());
+    let thread = handle.thread().clone();
+    let id = handle.join().unwrap();
+    assert_eq!(thread.id(), id);
+    assert_eq!(thread.clone().id(), id);
+}